@@ -5,19 +5,188 @@
 //! verified match performance data from the PerformanceOracle.
 //!
 //! ## Key Features:
-//! - ERC-721 compatible NFT
-//! - Dynamic on-chain stats that update with each match
-//! - Verifiable athlete resume for scouts and coaches
-//! - Non-transferable during active season (optional lockup)
-//! - Metadata stored on-chain for transparency
+//! - ERC-721 + ERC-721Metadata + ERC-165 (`safe_transfer_from` with
+//!   `onERC721Received` checks, `token_uri`, `supports_interface`)
+//! - Dynamic on-chain stats that update with each match, read from verified
+//!   PerformanceOracle records (permissionless, once per match)
+//!
+//! ## Stat Formulas (integer math only):
+//! - Each match feeds exponentially decayed "form" sums: on every match all
+//!   sums are multiplied by `decay_bps / 10_000` and the new match is added,
+//!   so the last few matches dominate. Sums are scaled by 10^6 and decayed
+//!   with `mul_div` (nearest rounding); ratios of two sums cancel the decay.
+//! - Derived metrics are fixed point x100: strike rate, runs per match,
+//!   economy (runs per over), bowling average, wickets per match and the
+//!   wearable top speed (km/h)
+//! - Each metric maps linearly to a 0-100 score between a zero point and a
+//!   full-marks point (clamped; inverted for economy and bowling average)
+//! - Power = strike rate & runs per match; Speed = wearable top speed &
+//!   strike rate; Accuracy = economy, bowling average & wickets per match,
+//!   blended with admin-configurable bps weights
+//! - Bowling figures only count from matches whose runs conceded were
+//!   recorded in the oracle (`recordExtendedMetrics`); without them economy
+//!   and bowling average would look perfect
+//! - Stats without any data for them stay at the neutral 50
+//! - Verifiable athlete resume for scouts and coaches, with a per-match
+//!   history (runs, wickets, balls, tier, match time) to show current form
+//! - Identity-safe minting: only verifiers mint, or athletes self-mint with a
+//!   verifier-signed EIP-712 attestation (KYC / federation issued); admins
+//!   can revoke profiles
+//! - Transfer modes: freely transferable, locked during admin-set season
+//!   windows, or fully soulbound; a transfer only moves ownership, the
+//!   attested athlete (and `athlete_to_token`) stays with the profile so a
+//!   buyer can't feed their own match records into it
+//! - Metadata stored on-chain for transparency: `token_uri` returns a
+//!   base64 JSON document with a generated SVG player card
+//! - SPP sink: holders burn SPP (`SPPToken::burn_from`) to unlock cosmetic
+//!   traits or raise a profile's visibility level, at admin-set prices
+
+use core::ops::Range;
 
 use stylus_sdk::{
+    abi::Bytes,
     alloy_primitives::{Address, U256, FixedBytes},
     prelude::*,
     msg,
     block,
+    call::Call,
+    types::AddressVM,
 };
 
+use crate::eip712;
+use crate::fixed_point::{checked_add, checked_mul, checked_sub, mul_div, Rounding};
+use crate::nft_metadata::AthleteCard;
+
+/// Cosmetic trait, unlocked once per profile by its owner
+pub const UPGRADE_COSMETIC: u8 = 0;
+
+/// Visibility boost, repeatable by anyone up to `MAX_VISIBILITY_LEVEL`
+pub const UPGRADE_VISIBILITY: u8 = 1;
+
+/// Highest profile visibility level
+pub const MAX_VISIBILITY_LEVEL: u64 = 10;
+
+/// Profiles transfer freely
+pub const TRANSFER_FREE: u8 = 0;
+
+/// Profiles are locked while a season window is active
+pub const TRANSFER_SEASON_LOCKED: u8 = 1;
+
+/// Profiles never transfer
+pub const TRANSFER_SOULBOUND: u8 = 2;
+
+/// Max season windows that haven't ended yet (bounds the per-transfer check)
+pub const MAX_OPEN_SEASONS: usize = 8;
+
+/// Denominator for stat weights and the recency decay (100% = 10,000 bps)
+pub const STAT_BPS_DENOMINATOR: u64 = 10_000;
+
+/// Stat of a profile without data for it
+pub const NEUTRAL_STAT: u64 = 50;
+
+/// Default weight older matches keep on each new match (85%)
+pub const DEFAULT_DECAY_BPS: u64 = 8_500;
+
+/// Default share of strike rate in Power (rest: runs per match)
+pub const DEFAULT_POWER_STRIKE_RATE_BPS: u64 = 5_000;
+
+/// Default share of wearable top speed in Speed (rest: strike rate)
+pub const DEFAULT_SPEED_WEARABLE_BPS: u64 = 7_000;
+
+/// Default share of economy in Accuracy
+pub const DEFAULT_ACCURACY_ECONOMY_BPS: u64 = 4_000;
+
+/// Default share of bowling average in Accuracy (rest: wickets per match)
+pub const DEFAULT_ACCURACY_AVERAGE_BPS: u64 = 3_000;
+
+/// Fixed-point scale of the form sums (10^6)
+const FORM_PRECISION: u64 = 1_000_000;
+
+/// Strike rate x100: 50 scores 0, 200 scores 100
+pub const STRIKE_RATE_RANGE: (u64, u64) = (5_000, 20_000);
+
+/// Runs per match x100: 0 scores 0, 60 scores 100
+pub const RUNS_PER_MATCH_RANGE: (u64, u64) = (0, 6_000);
+
+/// Top speed km/h x100: 15 scores 0, 35 scores 100
+pub const TOP_SPEED_RANGE: (u64, u64) = (1_500, 3_500);
+
+/// Economy (runs per over) x100: 12 scores 0, 4 scores 100
+pub const ECONOMY_RANGE: (u64, u64) = (1_200, 400);
+
+/// Bowling average x100: 50 scores 0, 15 scores 100
+pub const BOWLING_AVERAGE_RANGE: (u64, u64) = (5_000, 1_500);
+
+/// Wickets per match x100: 0 scores 0, 4 scores 100
+pub const WICKETS_PER_MATCH_RANGE: (u64, u64) = (0, 400);
+
+/// One match as fed into the form sums
+struct MatchSample {
+    runs: U256,
+    balls_faced: U256,
+    wickets: U256,
+    balls_bowled: U256,
+    runs_conceded: U256,
+    top_speed: U256, // km/h x100, 0 = no wearable reading
+    bowling_recorded: bool, // runs_conceded was recorded by the organizer
+}
+
+/// Stat formula weights in bps
+struct StatFormula {
+    decay: U256,
+    power_strike_rate: U256,
+    speed_wearable: U256,
+    accuracy_economy: U256,
+    accuracy_average: U256,
+}
+
+/// Recency-weighted metrics (x100), `None` when there is no data
+struct FormMetrics {
+    strike_rate: Option<U256>,
+    runs_per_match: Option<U256>,
+    economy: Option<U256>,
+    bowling_average: Option<U256>,
+    wickets_per_match: Option<U256>,
+    top_speed: Option<U256>,
+}
+
+/// ERC-165 interface ID
+pub const INTERFACE_ERC165: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+
+/// ERC-721 interface ID
+pub const INTERFACE_ERC721: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+
+/// ERC-721 Metadata interface ID
+pub const INTERFACE_ERC721_METADATA: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+
+/// `onERC721Received` selector, the expected receiver return value
+pub const ERC721_RECEIVED: [u8; 4] = [0x15, 0x0b, 0x7a, 0x02];
+
+/// EIP-712 domain version
+const DOMAIN_VERSION: &str = "1";
+
+/// Verifier attestation struct type for self-minting
+const ATTESTATION_TYPE: &[u8] =
+    b"AthleteAttestation(address athlete,string name,uint256 nonce,uint256 deadline)";
+
+sol_interface! {
+    interface ISPPToken {
+        function burnFrom(address from, uint256 amount) external;
+    }
+
+    interface IPerformanceOracle {
+        function getMatchProof(bytes32 match_id) external view returns (bytes32, bool, uint256);
+        function isMatchOverturned(bytes32 match_id) external view returns (bool);
+        function verifyPerformance(bytes32 match_id, address player) external view returns (bool);
+        function getPerformanceDetails(bytes32 match_id, address player) external view returns (uint256, uint256, uint256, uint256, uint256, uint8, uint256);
+        function getExtendedMetrics(bytes32 match_id, address player) external view returns (uint256, uint256, bool);
+    }
+
+    interface IERC721Receiver {
+        function onERC721Received(address operator, address from, uint256 token_id, bytes data) external returns (bytes4);
+    }
+}
+
 sol_storage! {
     /// Main AthleteNFT contract storage
     #[entrypoint]
@@ -34,7 +203,7 @@ sol_storage! {
         /// NFT symbol
         string symbol;
 
-        /// Token ID counter
+        /// Token ID counter (IDs start at 1; 0 means "no profile")
         uint256 next_token_id;
 
         /// Mapping from token ID to owner
@@ -55,8 +224,119 @@ sol_storage! {
         /// Mapping from athlete address to token ID
         mapping(address => uint256) athlete_to_token;
 
-        /// Total NFTs minted
+        /// Explicit profile existence flag per athlete
+        mapping(address => bool) has_profile;
+
+        /// Transfer policy (TRANSFER_FREE, TRANSFER_SEASON_LOCKED, TRANSFER_SOULBOUND)
+        uint8 transfer_mode;
+
+        /// Season windows (IDs start at 1) during which season-locked profiles can't move
+        mapping(uint256 => SeasonWindow) season_windows;
+        uint256 season_count;
+
+        /// Season windows that haven't ended yet; the only ones transfers check
+        uint256[] open_seasons;
+        mapping(uint256 => bool) season_open;
+
+        /// Matches already applied to a token's stats (token ID => match ID)
+        mapping(uint256 => mapping(bytes32 => bool)) applied_matches;
+
+        /// Per-match history per token, oldest first
+        mapping(uint256 => MatchEntry[]) match_history;
+
+        /// Recency-weighted form sums per token
+        mapping(uint256 => FormAccumulators) form;
+
+        /// Stat formula weights (defaults until configured)
+        StatWeights stat_weights;
+
+        /// Total profiles in existence (minted minus revoked)
         uint256 total_minted;
+
+        /// Accounts allowed to mint profiles and sign attestations
+        mapping(address => bool) verifiers;
+
+        /// Attestation nonces per athlete
+        mapping(address => uint256) attestation_nonces;
+
+        /// SPP Token contract address (upgrade payments are burned)
+        address token_contract;
+
+        /// Upgrade catalog (IDs start at 1)
+        mapping(uint256 => Upgrade) upgrades;
+        uint256 upgrade_count;
+
+        /// Unlock records (token ID => upgrade ID => record)
+        mapping(uint256 => mapping(uint256 => UnlockRecord)) unlocks;
+
+        /// Upgrade IDs unlocked per token
+        mapping(uint256 => uint256[]) token_upgrades;
+
+        /// Profile visibility level per token
+        mapping(uint256 => uint256) visibility_levels;
+
+        /// Total SPP burned on upgrades
+        uint256 total_upgrade_burned;
+
+        /// Optional off-chain profile page base (metadata `external_url` = base URI + token ID)
+        string base_uri;
+    }
+
+    /// Exponentially decayed sums, scaled by FORM_PRECISION
+    pub struct FormAccumulators {
+        uint256 matches;
+        uint256 runs;
+        uint256 balls_faced;
+        uint256 bowling_matches; // Matches with recorded bowling figures
+        uint256 wickets;
+        uint256 balls_bowled;
+        uint256 runs_conceded;
+        uint256 speed_total; // km/h x100
+        uint256 speed_readings;
+    }
+
+    /// Stat formula configuration
+    pub struct StatWeights {
+        bool configured;
+        uint256 decay_bps;
+        uint256 power_strike_rate_bps;
+        uint256 speed_wearable_bps;
+        uint256 accuracy_economy_bps;
+        uint256 accuracy_average_bps;
+    }
+
+    /// One applied match
+    pub struct MatchEntry {
+        bytes32 match_id;
+        uint256 runs;
+        uint256 wickets;
+        uint256 balls_faced;
+        uint256 balls_bowled;
+        uint8 tier;
+        uint256 timestamp; // Match finalization time
+    }
+
+    /// Active season, inclusive start and exclusive end timestamps
+    pub struct SeasonWindow {
+        uint256 start;
+        uint256 end;
+    }
+
+    /// Catalog entry
+    pub struct Upgrade {
+        string name;
+        uint8 kind; // UPGRADE_COSMETIC or UPGRADE_VISIBILITY
+        uint256 price; // SPP base units burned per unlock
+        bool active;
+        bool exists;
+    }
+
+    /// What a token has unlocked of one upgrade
+    pub struct UnlockRecord {
+        bool unlocked;
+        uint256 count; // Purchases (visibility boosts can repeat)
+        uint256 first_unlocked_at;
+        uint256 total_burned;
     }
 
     /// Athlete statistics (dynamic/computational)
@@ -118,6 +398,52 @@ sol! {
         uint256 wickets
     );
 
+    event BaseURIUpdated(string baseUri);
+
+    event VerifierUpdated(address indexed verifier, bool enabled);
+
+    event TransferModeUpdated(uint8 mode);
+
+    event StatWeightsUpdated(
+        uint256 decayBps,
+        uint256 powerStrikeRateBps,
+        uint256 speedWearableBps,
+        uint256 accuracyEconomyBps,
+        uint256 accuracyAverageBps
+    );
+
+    event SeasonWindowSet(
+        uint256 indexed seasonId,
+        uint256 start,
+        uint256 end
+    );
+
+    event ProfileRevoked(
+        uint256 indexed tokenId,
+        address indexed athlete
+    );
+
+    event UpgradeListed(
+        uint256 indexed upgradeId,
+        string name,
+        uint8 kind,
+        uint256 price
+    );
+
+    event UpgradeUpdated(
+        uint256 indexed upgradeId,
+        uint256 price,
+        bool active
+    );
+
+    event UpgradeUnlocked(
+        uint256 indexed tokenId,
+        uint256 indexed upgradeId,
+        address indexed buyer,
+        uint256 burned,
+        uint256 visibilityLevel
+    );
+
     // Errors
     error TokenDoesNotExist();
     error NotTokenOwner();
@@ -125,6 +451,26 @@ sol! {
     error AlreadyHasProfile();
     error InvalidAddress();
     error Unauthorized();
+    error TransferToNonReceiver();
+    error ApprovalToOwner();
+    error AttestationExpired();
+    error InvalidAttestation();
+    error InvalidTransferMode();
+    error InvalidSeasonWindow();
+    error SeasonNotFound();
+    error TooManyOpenSeasons();
+    error TransferLocked();
+    error SoulboundToken();
+    error MatchNotFinalized();
+    error MatchOverturned();
+    error PerformanceNotVerified();
+    error MatchAlreadyApplied();
+    error InvalidStatWeights();
+    error InvalidUpgradeKind();
+    error UpgradeNotFound();
+    error UpgradeInactive();
+    error UpgradeAlreadyUnlocked();
+    error MaxVisibilityReached();
 }
 
 #[public]
@@ -170,11 +516,66 @@ impl AthleteNFT {
         Ok(self.symbol.get_string())
     }
 
+    /// Get the fully on-chain metadata of a token
+    /// @return `data:application/json;base64,` JSON with an SVG card image
+    #[selector(name = "tokenURI")]
+    pub fn token_uri(&self, token_id: U256) -> Result<String, Vec<u8>> {
+        self.owner_of(token_id)?;
+
+        let base_uri = self.base_uri.get_string();
+        let external_url = if base_uri.is_empty() {
+            None
+        } else {
+            Some(format!("{}{}", base_uri, token_id))
+        };
+
+        let stats = self.athlete_stats.get(token_id);
+        let name = stats.athlete_name.get_string();
+        let card = AthleteCard {
+            token_id,
+            name: &name,
+            power: stats.power.get(),
+            speed: stats.speed.get(),
+            accuracy: stats.accuracy.get(),
+            matches_played: stats.matches_played.get(),
+            total_runs: stats.total_runs.get(),
+            total_wickets: stats.total_wickets.get(),
+            highest_score: stats.highest_score.get(),
+            best_bowling: stats.best_bowling.get(),
+            visibility_level: self.visibility_levels.get(token_id),
+            external_url,
+        };
+
+        Ok(card.to_token_uri())
+    }
+
+    /// Set the off-chain profile page base URI used as `external_url` (admin only)
+    pub fn set_base_uri(&mut self, base_uri: String) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.base_uri.set_str(&base_uri);
+
+        evm::log(BaseURIUpdated { baseUri: base_uri });
+
+        Ok(())
+    }
+
+    /// ERC-165: ERC-165, ERC-721 and ERC-721 Metadata are supported
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> Result<bool, Vec<u8>> {
+        Ok(is_supported_interface(interface_id.0))
+    }
+
     /// Approve address to transfer token
     pub fn approve(&mut self, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
         let owner = self.owner_of(token_id)?;
         let caller = msg::sender();
 
+        if to == owner {
+            return Err(ApprovalToOwner {}.encode());
+        }
+
         if caller != owner && !self.is_approved_for_all(owner, caller)? {
             return Err(NotAuthorized {}.encode());
         }
@@ -231,29 +632,36 @@ impl AthleteNFT {
         to: Address,
         token_id: U256,
     ) -> Result<(), Vec<u8>> {
-        let caller = msg::sender();
-        let owner = self.owner_of(token_id)?;
-
-        // Check authorization
-        if owner != from {
-            return Err(NotTokenOwner {}.encode());
-        }
-
-        let is_authorized = caller == owner
-            || self.get_approved(token_id)? == caller
-            || self.is_approved_for_all(owner, caller)?;
+        self._checked_transfer(from, to, token_id)
+    }
 
-        if !is_authorized {
-            return Err(NotAuthorized {}.encode());
-        }
+    /// Transfer token, checking that a contract recipient accepts it
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.safe_transfer_from_with_data(from, to, token_id, Bytes(Vec::new()))
+    }
 
-        self._transfer(from, to, token_id)?;
-        Ok(())
+    /// Transfer token with data forwarded to `onERC721Received`
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from_with_data(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        self._checked_transfer(from, to, token_id)?;
+        self._check_on_erc721_received(from, to, token_id, data)
     }
 
     // ==================== Athlete Profile Functions ====================
 
-    /// Mint a new athlete profile NFT
+    /// Mint a new athlete profile NFT (verifier or owner only)
     /// @param athlete Athlete's address
     /// @param athleteName Athlete's name
     /// @return Token ID
@@ -262,109 +670,300 @@ impl AthleteNFT {
         athlete: Address,
         athlete_name: String,
     ) -> Result<U256, Vec<u8>> {
-        // Check if athlete already has a profile
-        let existing_token = self.athlete_to_token.get(athlete);
-        if existing_token > U256::from(0) {
-            return Err(AlreadyHasProfile {}.encode());
+        let caller = msg::sender();
+        if !self.verifiers.get(caller) && caller != self.owner.get() {
+            return Err(Unauthorized {}.encode());
         }
 
-        // Get next token ID
-        let token_id = self.next_token_id.get();
+        self._mint_profile(athlete, athlete_name)
+    }
 
-        // Mint NFT
-        self.owners.setter(token_id).set(athlete);
-        let balance = self.balances.get(athlete);
-        self.balances.setter(athlete).set(balance + U256::from(1));
+    /// Self-mint the caller's profile with a verifier-signed attestation
+    /// @param athleteName Name attested by the verifier
+    /// @param deadline Last timestamp at which the attestation is valid
+    /// @param v Signature recovery id (27 or 28)
+    /// @param r Signature r
+    /// @param s Signature s
+    /// @return Token ID
+    pub fn mint_with_attestation(
+        &mut self,
+        athlete_name: String,
+        deadline: U256,
+        v: u8,
+        r: FixedBytes<32>,
+        s: FixedBytes<32>,
+    ) -> Result<U256, Vec<u8>> {
+        let athlete = msg::sender();
 
-        // Initialize athlete stats
-        let mut stats = self.athlete_stats.setter(token_id);
-        stats.athlete.set(athlete);
-        stats.athlete_name.set_str(&athlete_name);
-        stats.power.set(U256::from(50)); // Default starting stats
-        stats.speed.set(U256::from(50));
-        stats.accuracy.set(U256::from(50));
-        stats.matches_played.set(U256::from(0));
-        stats.total_runs.set(U256::from(0));
-        stats.total_wickets.set(U256::from(0));
-        stats.highest_score.set(U256::from(0));
-        stats.best_bowling.set(U256::from(0));
-        stats.last_updated.set(U256::from(block::timestamp()));
-        stats.is_active.set(true);
+        if U256::from(block::timestamp()) > deadline {
+            return Err(AttestationExpired {}.encode());
+        }
 
-        // Map athlete to token
-        self.athlete_to_token.setter(athlete).set(token_id);
+        let nonce = self.attestation_nonces.get(athlete);
+        let struct_hash = eip712::hash_words(&[
+            eip712::hash_bytes(ATTESTATION_TYPE).0,
+            eip712::address_word(athlete),
+            eip712::hash_bytes(athlete_name.as_bytes()).0,
+            eip712::uint_word(nonce),
+            eip712::uint_word(deadline),
+        ]);
+        let domain_separator = eip712::domain_separator(&self.name.get_string(), DOMAIN_VERSION);
+        let digest = eip712::typed_data_hash(domain_separator, struct_hash);
+
+        let signer = eip712::recover_signer(digest, v, r, s)?;
+        if !self.verifiers.get(signer) {
+            return Err(InvalidAttestation {}.encode());
+        }
+
+        self.attestation_nonces.setter(athlete).set(checked_add(nonce, U256::from(1))?);
+        self._mint_profile(athlete, athlete_name)
+    }
+
+    /// Burn a profile, e.g. an impersonation or revoked attestation (admin only)
+    /// The athlete can be issued a new profile afterwards.
+    pub fn revoke_profile(&mut self, token_id: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let holder = self.owner_of(token_id)?;
+        let athlete = self.athlete_stats.get(token_id).athlete.get();
+
+        if self.token_approvals.get(token_id) != Address::ZERO {
+            self.token_approvals.setter(token_id).set(Address::ZERO);
+        }
+
+        let balance = self.balances.get(holder);
+        self.balances.setter(holder).set(checked_sub(balance, U256::from(1))?);
+        self.owners.setter(token_id).set(Address::ZERO);
+
+        if self.athlete_to_token.get(athlete) == token_id {
+            self.athlete_to_token.setter(athlete).set(U256::from(0));
+            self.has_profile.setter(athlete).set(false);
+        }
+        self.athlete_stats.setter(token_id).is_active.set(false);
 
-        // Increment counters
-        self.next_token_id.set(token_id + U256::from(1));
         let total = self.total_minted.get();
-        self.total_minted.set(total + U256::from(1));
+        self.total_minted.set(checked_sub(total, U256::from(1))?);
 
-        // Emit events
         evm::log(Transfer {
-            from: Address::ZERO,
-            to: athlete,
+            from: holder,
+            to: Address::ZERO,
             tokenId: token_id,
         });
 
-        evm::log(AthleteProfileMinted {
+        evm::log(ProfileRevoked {
             tokenId: token_id,
             athlete,
-            name: athlete_name,
         });
 
-        Ok(token_id)
+        Ok(())
     }
 
-    /// Update athlete stats based on match performance
-    /// @param tokenId Athlete's NFT token ID
-    /// @param matchId Match identifier
-    /// @param runs Runs scored in match
-    /// @param wickets Wickets taken in match
-    pub fn update_stats_from_match(
+    /// Grant or remove the verifier role (admin only)
+    pub fn set_verifier(&mut self, verifier: Address, enabled: bool) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if verifier == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.verifiers.setter(verifier).set(enabled);
+
+        evm::log(VerifierUpdated { verifier, enabled });
+
+        Ok(())
+    }
+
+    /// Check whether an account is a verifier
+    pub fn is_verifier(&self, account: Address) -> Result<bool, Vec<u8>> {
+        Ok(self.verifiers.get(account))
+    }
+
+    /// Get an athlete's next attestation nonce
+    pub fn attestation_nonce(&self, athlete: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.attestation_nonces.get(athlete))
+    }
+
+    // ==================== Transfer Policy ====================
+
+    /// Set the transfer policy (admin only)
+    /// @param mode TRANSFER_FREE, TRANSFER_SEASON_LOCKED or TRANSFER_SOULBOUND
+    pub fn set_transfer_mode(&mut self, mode: u8) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if mode > TRANSFER_SOULBOUND {
+            return Err(InvalidTransferMode {}.encode());
+        }
+
+        self.transfer_mode.set(mode);
+
+        evm::log(TransferModeUpdated { mode });
+
+        Ok(())
+    }
+
+    /// Add a season window (admin only)
+    /// @return Season ID
+    pub fn add_season_window(&mut self, start: U256, end: U256) -> Result<U256, Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if start >= end {
+            return Err(InvalidSeasonWindow {}.encode());
+        }
+
+        let season_id = checked_add(self.season_count.get(), U256::from(1))?;
+        self.season_count.set(season_id);
+        self._set_season_window(season_id, start, end)?;
+
+        Ok(season_id)
+    }
+
+    /// Move or cancel a season window (admin only, start = end = 0 cancels)
+    pub fn update_season_window(
         &mut self,
-        token_id: U256,
-        match_id: FixedBytes<32>,
-        runs: U256,
-        wickets: U256,
+        season_id: U256,
+        start: U256,
+        end: U256,
     ) -> Result<(), Vec<u8>> {
-        // Only oracle or owner can update stats
-        let caller = msg::sender();
-        if caller != self.oracle_contract.get() && caller != self.owner.get() {
+        if msg::sender() != self.owner.get() {
             return Err(Unauthorized {}.encode());
         }
 
-        // Get athlete stats
-        let mut stats = self.athlete_stats.setter(token_id);
+        if season_id == U256::from(0) || season_id > self.season_count.get() {
+            return Err(SeasonNotFound {}.encode());
+        }
 
-        // Update match count
-        let matches = stats.matches_played.get();
-        stats.matches_played.set(matches + U256::from(1));
+        let cancelled = start == U256::from(0) && end == U256::from(0);
+        if !cancelled && start >= end {
+            return Err(InvalidSeasonWindow {}.encode());
+        }
+
+        self._set_season_window(season_id, start, end)
+    }
 
-        // Update total runs
-        let total_runs = stats.total_runs.get();
-        stats.total_runs.set(total_runs + runs);
+    /// Get the transfer policy
+    pub fn get_transfer_mode(&self) -> Result<u8, Vec<u8>> {
+        Ok(self.transfer_mode.get())
+    }
 
-        // Update highest score
-        let highest = stats.highest_score.get();
-        if runs > highest {
-            stats.highest_score.set(runs);
+    /// Get a season window
+    /// @return (start, end)
+    pub fn get_season_window(&self, season_id: U256) -> Result<(U256, U256), Vec<u8>> {
+        if season_id == U256::from(0) || season_id > self.season_count.get() {
+            return Err(SeasonNotFound {}.encode());
         }
 
-        // Update total wickets
-        let total_wickets = stats.total_wickets.get();
-        stats.total_wickets.set(total_wickets + wickets);
+        let window = self.season_windows.get(season_id);
+        Ok((window.start.get(), window.end.get()))
+    }
+
+    /// Get the number of season windows
+    pub fn get_season_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.season_count.get())
+    }
+
+    /// Check whether a season window is active right now
+    pub fn is_season_active(&self) -> Result<bool, Vec<u8>> {
+        Ok(self._is_season_active())
+    }
+
+    /// Check whether profiles can be transferred right now
+    pub fn transfers_allowed(&self) -> Result<bool, Vec<u8>> {
+        Ok(self._check_transfer_policy().is_ok())
+    }
 
-        // Update best bowling
-        let best_bowling = stats.best_bowling.get();
-        if wickets > best_bowling {
-            stats.best_bowling.set(wickets);
+    /// Update athlete stats from a verified oracle performance (callable by anyone)
+    /// The match must be finalized and not overturned, the profile's athlete
+    /// must have a verified record in it, and each match counts once.
+    /// @param tokenId Athlete's NFT token ID
+    /// @param matchId Match identifier
+    pub fn update_stats_from_match(
+        &mut self,
+        token_id: U256,
+        match_id: FixedBytes<32>,
+    ) -> Result<(), Vec<u8>> {
+        self.owner_of(token_id)?;
+
+        if self.applied_matches.get(token_id).get(match_id) {
+            return Err(MatchAlreadyApplied {}.encode());
         }
 
-        // Recalculate dynamic stats
-        let new_power = self._calculate_power(&stats)?;
-        let new_speed = self._calculate_speed(&stats)?;
-        let new_accuracy = self._calculate_accuracy(&stats)?;
+        let athlete = self.athlete_stats.get(token_id).athlete.get();
 
+        let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+        let (_, is_finalized, finalized_at) = oracle.get_match_proof(Call::new(), match_id)?;
+        if !is_finalized {
+            return Err(MatchNotFinalized {}.encode());
+        }
+        if oracle.is_match_overturned(Call::new(), match_id)? {
+            return Err(MatchOverturned {}.encode());
+        }
+        if !oracle.verify_performance(Call::new(), match_id, athlete)? {
+            return Err(PerformanceNotVerified {}.encode());
+        }
+        let (runs, wickets, balls_faced, balls_bowled, _, tier, _) =
+            oracle.get_performance_details(Call::new(), match_id, athlete)?;
+        let (runs_conceded, top_speed, bowling_recorded) =
+            oracle.get_extended_metrics(Call::new(), match_id, athlete)?;
+
+        self.applied_matches.setter(token_id).setter(match_id).set(true);
+
+        // Append to the match history
+        let mut history = self.match_history.setter(token_id);
+        let mut entry = history.grow();
+        entry.match_id.set(match_id);
+        entry.runs.set(runs);
+        entry.wickets.set(wickets);
+        entry.balls_faced.set(balls_faced);
+        entry.balls_bowled.set(balls_bowled);
+        entry.tier.set(tier);
+        entry.timestamp.set(finalized_at);
+
+        // Update aggregates
+        {
+            let mut stats = self.athlete_stats.setter(token_id);
+
+            let matches = stats.matches_played.get();
+            stats.matches_played.set(checked_add(matches, U256::from(1))?);
+
+            let total_runs = stats.total_runs.get();
+            stats.total_runs.set(checked_add(total_runs, runs)?);
+
+            if runs > stats.highest_score.get() {
+                stats.highest_score.set(runs);
+            }
+
+            let total_wickets = stats.total_wickets.get();
+            stats.total_wickets.set(checked_add(total_wickets, wickets)?);
+
+            if wickets > stats.best_bowling.get() {
+                stats.best_bowling.set(wickets);
+            }
+        }
+
+        // Fold the match into the form sums and recalculate dynamic stats
+        self._update_form(
+            token_id,
+            &MatchSample {
+                runs,
+                balls_faced,
+                wickets,
+                balls_bowled,
+                runs_conceded,
+                top_speed,
+                bowling_recorded,
+            },
+        )?;
+        let (new_power, new_speed, new_accuracy) = self._calculate_stats(token_id)?;
+
+        let mut stats = self.athlete_stats.setter(token_id);
         stats.power.set(new_power);
         stats.speed.set(new_speed);
         stats.accuracy.set(new_accuracy);
@@ -389,6 +988,129 @@ impl AthleteNFT {
         Ok(())
     }
 
+    /// Configure the stat formulas (admin only)
+    /// Applies from the next match update of each profile.
+    /// @param decayBps Weight older matches keep on each new match (0 < x < 10,000)
+    /// @param powerStrikeRateBps Share of strike rate in Power (rest: runs per match)
+    /// @param speedWearableBps Share of wearable top speed in Speed (rest: strike rate)
+    /// @param accuracyEconomyBps Share of economy in Accuracy
+    /// @param accuracyAverageBps Share of bowling average in Accuracy (rest: wickets per match)
+    pub fn set_stat_weights(
+        &mut self,
+        decay_bps: U256,
+        power_strike_rate_bps: U256,
+        speed_wearable_bps: U256,
+        accuracy_economy_bps: U256,
+        accuracy_average_bps: U256,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let bps = U256::from(STAT_BPS_DENOMINATOR);
+        if decay_bps == U256::from(0)
+            || decay_bps >= bps
+            || power_strike_rate_bps > bps
+            || speed_wearable_bps > bps
+            || checked_add(accuracy_economy_bps, accuracy_average_bps)? > bps
+        {
+            return Err(InvalidStatWeights {}.encode());
+        }
+
+        let mut weights = self.stat_weights.setter();
+        weights.configured.set(true);
+        weights.decay_bps.set(decay_bps);
+        weights.power_strike_rate_bps.set(power_strike_rate_bps);
+        weights.speed_wearable_bps.set(speed_wearable_bps);
+        weights.accuracy_economy_bps.set(accuracy_economy_bps);
+        weights.accuracy_average_bps.set(accuracy_average_bps);
+
+        evm::log(StatWeightsUpdated {
+            decayBps: decay_bps,
+            powerStrikeRateBps: power_strike_rate_bps,
+            speedWearableBps: speed_wearable_bps,
+            accuracyEconomyBps: accuracy_economy_bps,
+            accuracyAverageBps: accuracy_average_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Get the stat formula weights
+    /// @return (decayBps, powerStrikeRateBps, speedWearableBps, accuracyEconomyBps, accuracyAverageBps)
+    pub fn get_stat_weights(&self) -> Result<(U256, U256, U256, U256, U256), Vec<u8>> {
+        let formula = self._stat_formula();
+        Ok((
+            formula.decay,
+            formula.power_strike_rate,
+            formula.speed_wearable,
+            formula.accuracy_economy,
+            formula.accuracy_average,
+        ))
+    }
+
+    /// Get a profile's recency-weighted form (all x100, 0 when there is no data)
+    /// @return (strikeRate, runsPerMatch, economy, bowlingAverage, wicketsPerMatch, topSpeed)
+    pub fn get_form(&self, token_id: U256) -> Result<(U256, U256, U256, U256, U256, U256), Vec<u8>> {
+        let metrics = self._form_metrics(token_id)?;
+        Ok((
+            metrics.strike_rate.unwrap_or_default(),
+            metrics.runs_per_match.unwrap_or_default(),
+            metrics.economy.unwrap_or_default(),
+            metrics.bowling_average.unwrap_or_default(),
+            metrics.wickets_per_match.unwrap_or_default(),
+            metrics.top_speed.unwrap_or_default(),
+        ))
+    }
+
+    /// Get the number of matches in a token's history
+    pub fn get_match_history_count(&self, token_id: U256) -> Result<U256, Vec<u8>> {
+        Ok(U256::from(self.match_history.get(token_id).len()))
+    }
+
+    /// Get a page of a token's match history, oldest first
+    /// @param tokenId NFT token ID
+    /// @param offset Index of the first entry
+    /// @param limit Maximum number of entries
+    /// @return [(matchId, runs, wickets, ballsFaced, ballsBowled, tier, timestamp)]
+    pub fn get_match_history(
+        &self,
+        token_id: U256,
+        offset: U256,
+        limit: U256,
+    ) -> Result<Vec<(FixedBytes<32>, U256, U256, U256, U256, u8, U256)>, Vec<u8>> {
+        let len = self.match_history.get(token_id).len();
+
+        let mut entries = Vec::new();
+        for index in history_page(len, offset, limit) {
+            entries.push(self._match_entry(token_id, index));
+        }
+        Ok(entries)
+    }
+
+    /// Get a token's most recent matches, newest first
+    /// @param tokenId NFT token ID
+    /// @param count Maximum number of entries
+    /// @return [(matchId, runs, wickets, ballsFaced, ballsBowled, tier, timestamp)]
+    pub fn get_recent_matches(
+        &self,
+        token_id: U256,
+        count: U256,
+    ) -> Result<Vec<(FixedBytes<32>, U256, U256, U256, U256, u8, U256)>, Vec<u8>> {
+        let range = recent_history(self.match_history.get(token_id).len(), count);
+
+        let mut entries = Vec::with_capacity(range.len());
+        for index in range.rev() {
+            entries.push(self._match_entry(token_id, index));
+        }
+        Ok(entries)
+    }
+
+    /// Check whether a match has already been applied to a token's stats
+    pub fn is_match_applied(&self, token_id: U256, match_id: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        Ok(self.applied_matches.get(token_id).get(match_id))
+    }
+
     /// Get athlete stats
     /// @param tokenId NFT token ID
     /// @return (power, speed, accuracy, matchesPlayed, totalRuns, totalWickets)
@@ -435,33 +1157,320 @@ impl AthleteNFT {
 
     /// Get token ID for an athlete address
     /// @param athlete Athlete's address
-    /// @return Token ID (0 if no profile)
+    /// @return Token ID (0 if no profile; use `has_profile` to be explicit)
     pub fn get_athlete_token_id(&self, athlete: Address) -> Result<U256, Vec<u8>> {
         Ok(self.athlete_to_token.get(athlete))
     }
 
+    /// Check whether an athlete has a profile
+    pub fn has_profile(&self, athlete: Address) -> Result<bool, Vec<u8>> {
+        Ok(self._has_profile(athlete))
+    }
+
     /// Get total minted profiles
     pub fn total_supply(&self) -> Result<U256, Vec<u8>> {
         Ok(self.total_minted.get())
     }
 
-    // ==================== Internal Functions ====================
+    // ==================== Upgrade Functions ====================
+
+    /// Set the SPP token whose burns pay for upgrades (admin only)
+    pub fn set_token_contract(&mut self, token_contract: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if token_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.token_contract.set(token_contract);
+        Ok(())
+    }
+
+    /// Add an upgrade to the catalog (admin only)
+    /// @param name Display name of the trait or boost
+    /// @param kind UPGRADE_COSMETIC or UPGRADE_VISIBILITY
+    /// @param price SPP (base units) burned per unlock
+    /// @return Upgrade ID
+    pub fn create_upgrade(
+        &mut self,
+        name: String,
+        kind: u8,
+        price: U256,
+    ) -> Result<U256, Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if kind != UPGRADE_COSMETIC && kind != UPGRADE_VISIBILITY {
+            return Err(InvalidUpgradeKind {}.encode());
+        }
+
+        let upgrade_id = checked_add(self.upgrade_count.get(), U256::from(1))?;
+        self.upgrade_count.set(upgrade_id);
+
+        let mut upgrade = self.upgrades.setter(upgrade_id);
+        upgrade.name.set_str(&name);
+        upgrade.kind.set(kind);
+        upgrade.price.set(price);
+        upgrade.active.set(true);
+        upgrade.exists.set(true);
+
+        evm::log(UpgradeListed {
+            upgradeId: upgrade_id,
+            name,
+            kind,
+            price,
+        });
+
+        Ok(upgrade_id)
+    }
+
+    /// Change an upgrade's price or availability (admin only)
+    pub fn update_upgrade(
+        &mut self,
+        upgrade_id: U256,
+        price: U256,
+        active: bool,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if !self.upgrades.get(upgrade_id).exists.get() {
+            return Err(UpgradeNotFound {}.encode());
+        }
+
+        let mut upgrade = self.upgrades.setter(upgrade_id);
+        upgrade.price.set(price);
+        upgrade.active.set(active);
+
+        evm::log(UpgradeUpdated {
+            upgradeId: upgrade_id,
+            price,
+            active,
+        });
+
+        Ok(())
+    }
+
+    /// Burn the caller's SPP to unlock an upgrade for a profile
+    /// The caller must approve (or permit) this contract for the price.
+    /// Cosmetic traits: token owner or approved, once per token.
+    /// Visibility boosts: anyone (e.g. fans), +1 level per purchase.
+    /// @param tokenId Profile to upgrade
+    /// @param upgradeId Catalog entry
+    /// @return Visibility level after the unlock
+    pub fn unlock_upgrade(&mut self, token_id: U256, upgrade_id: U256) -> Result<U256, Vec<u8>> {
+        let buyer = msg::sender();
+        let owner = self.owner_of(token_id)?;
+
+        let (kind, price) = {
+            let upgrade = self.upgrades.get(upgrade_id);
+            if !upgrade.exists.get() {
+                return Err(UpgradeNotFound {}.encode());
+            }
+            if !upgrade.active.get() {
+                return Err(UpgradeInactive {}.encode());
+            }
+            (upgrade.kind.get(), upgrade.price.get())
+        };
+
+        let (unlocked, count, total_burned) = {
+            let record = self.unlocks.get(token_id).get(upgrade_id);
+            (record.unlocked.get(), record.count.get(), record.total_burned.get())
+        };
+
+        let mut visibility_level = self.visibility_levels.get(token_id);
+        if kind == UPGRADE_COSMETIC {
+            let is_authorized = buyer == owner
+                || self.token_approvals.get(token_id) == buyer
+                || self.is_approved_for_all(owner, buyer)?;
+            if !is_authorized {
+                return Err(NotAuthorized {}.encode());
+            }
+            if unlocked {
+                return Err(UpgradeAlreadyUnlocked {}.encode());
+            }
+        } else {
+            if visibility_level >= U256::from(MAX_VISIBILITY_LEVEL) {
+                return Err(MaxVisibilityReached {}.encode());
+            }
+            visibility_level = checked_add(visibility_level, U256::from(1))?;
+            self.visibility_levels.setter(token_id).set(visibility_level);
+        }
+
+        // Record the unlock
+        if !unlocked {
+            self.token_upgrades.setter(token_id).push(upgrade_id);
+        }
+        let mut record = self.unlocks.setter(token_id).setter(upgrade_id);
+        if !unlocked {
+            record.unlocked.set(true);
+            record.first_unlocked_at.set(U256::from(block::timestamp()));
+        }
+        record.count.set(checked_add(count, U256::from(1))?);
+        record.total_burned.set(checked_add(total_burned, price)?);
+
+        let burned = self.total_upgrade_burned.get();
+        self.total_upgrade_burned.set(checked_add(burned, price)?);
+
+        // Burn the payment
+        if price > U256::from(0) {
+            let token_contract = self.token_contract.get();
+            if token_contract == Address::ZERO {
+                return Err(InvalidAddress {}.encode());
+            }
+            let token = ISPPToken::new(token_contract);
+            token.burn_from(Call::new_in(self), buyer, price)?;
+        }
+
+        evm::log(UpgradeUnlocked {
+            tokenId: token_id,
+            upgradeId: upgrade_id,
+            buyer,
+            burned: price,
+            visibilityLevel: visibility_level,
+        });
+
+        Ok(visibility_level)
+    }
+
+    /// Get an upgrade from the catalog
+    /// @return (name, kind, price, active)
+    pub fn get_upgrade(&self, upgrade_id: U256) -> Result<(String, u8, U256, bool), Vec<u8>> {
+        let upgrade = self.upgrades.get(upgrade_id);
+        if !upgrade.exists.get() {
+            return Err(UpgradeNotFound {}.encode());
+        }
+
+        Ok((
+            upgrade.name.get_string(),
+            upgrade.kind.get(),
+            upgrade.price.get(),
+            upgrade.active.get(),
+        ))
+    }
+
+    /// Get the number of catalog entries
+    pub fn get_upgrade_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.upgrade_count.get())
+    }
+
+    /// Get the upgrade IDs a token has unlocked
+    pub fn get_token_upgrades(&self, token_id: U256) -> Result<Vec<U256>, Vec<u8>> {
+        let ids = self.token_upgrades.get(token_id);
+        let mut result = Vec::with_capacity(ids.len());
+        for i in 0..ids.len() {
+            result.push(ids.get(i).unwrap_or_default());
+        }
+        Ok(result)
+    }
+
+    /// Get a token's unlock record for an upgrade
+    /// @return (unlocked, count, firstUnlockedAt, totalBurned)
+    pub fn get_unlock(
+        &self,
+        token_id: U256,
+        upgrade_id: U256,
+    ) -> Result<(bool, U256, U256, U256), Vec<u8>> {
+        let record = self.unlocks.get(token_id).get(upgrade_id);
+        Ok((
+            record.unlocked.get(),
+            record.count.get(),
+            record.first_unlocked_at.get(),
+            record.total_burned.get(),
+        ))
+    }
+
+    /// Get a profile's visibility level (0 to MAX_VISIBILITY_LEVEL)
+    pub fn get_visibility_level(&self, token_id: U256) -> Result<U256, Vec<u8>> {
+        Ok(self.visibility_levels.get(token_id))
+    }
+
+    /// Get total SPP burned on upgrades
+    pub fn total_upgrade_burned(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_upgrade_burned.get())
+    }
+}
+
+// ==================== Internal Functions ====================
+
+impl AthleteNFT {
+    /// Transfer after checking ownership and caller authorization
+    fn _checked_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        let owner = self.owner_of(token_id)?;
+
+        // Check authorization
+        if owner != from {
+            return Err(NotTokenOwner {}.encode());
+        }
+
+        let is_authorized = caller == owner
+            || self.get_approved(token_id)? == caller
+            || self.is_approved_for_all(owner, caller)?;
+
+        if !is_authorized {
+            return Err(NotAuthorized {}.encode());
+        }
+
+        self._transfer(from, to, token_id)
+    }
+
+    /// Require contract recipients to return the `onERC721Received` selector
+    fn _check_on_erc721_received(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        if !to.has_code() {
+            return Ok(());
+        }
+
+        let receiver = IERC721Receiver::new(to);
+        match receiver.on_erc_721_received(Call::new_in(self), msg::sender(), from, token_id, data) {
+            Ok(selector) if selector.0 == ERC721_RECEIVED => Ok(()),
+            _ => Err(TransferToNonReceiver {}.encode()),
+        }
+    }
 
     /// Internal transfer function
+    /// Only ownership moves: the attested athlete whose oracle records feed
+    /// the stats stays fixed, so holders (marketplaces, escrows, collectors)
+    /// may hold any number of profiles.
     fn _transfer(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
         if to == Address::ZERO {
             return Err(InvalidAddress {}.encode());
         }
 
-        // Clear approvals
-        self.token_approvals.setter(token_id).set(Address::ZERO);
+        self._check_transfer_policy()?;
+
+        // Clear the single-token approval
+        if self.token_approvals.get(token_id) != Address::ZERO {
+            self.token_approvals.setter(token_id).set(Address::ZERO);
+
+            evm::log(Approval {
+                owner: from,
+                approved: Address::ZERO,
+                tokenId: token_id,
+            });
+        }
 
         // Update balances
         let from_balance = self.balances.get(from);
-        self.balances.setter(from).set(from_balance - U256::from(1));
+        self.balances.setter(from).set(checked_sub(from_balance, U256::from(1))?);
 
         let to_balance = self.balances.get(to);
-        self.balances.setter(to).set(to_balance + U256::from(1));
+        self.balances.setter(to).set(checked_add(to_balance, U256::from(1))?);
 
         // Update owner
         self.owners.setter(token_id).set(to);
@@ -475,58 +1484,389 @@ impl AthleteNFT {
         Ok(())
     }
 
-    /// Calculate power stat (based on runs and highest score)
-    fn _calculate_power(&self, stats: &StorageGuard<AthleteStats>) -> Result<U256, Vec<u8>> {
-        let matches = stats.matches_played.get();
-        if matches == U256::from(0) {
-            return Ok(U256::from(50));
+    /// Mint a profile NFT to an athlete without a profile
+    fn _mint_profile(&mut self, athlete: Address, athlete_name: String) -> Result<U256, Vec<u8>> {
+        if athlete == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
         }
 
-        let total_runs = stats.total_runs.get();
-        let avg_runs = total_runs / matches;
-        let highest = stats.highest_score.get();
+        // Check if athlete already has a profile
+        if self._has_profile(athlete) {
+            return Err(AlreadyHasProfile {}.encode());
+        }
 
-        // Power = (avg_runs + highest/2) capped at 100
-        let power = avg_runs + (highest / U256::from(2));
-        Ok(if power > U256::from(100) {
-            U256::from(100)
-        } else {
-            power
-        })
+        // Get next token ID, never issuing the 0 sentinel (e.g. un-initialized
+        // counters on legacy deployments)
+        let mut token_id = self.next_token_id.get();
+        if token_id == U256::from(0) {
+            token_id = U256::from(1);
+        }
+
+        // Mint NFT
+        self.owners.setter(token_id).set(athlete);
+        let balance = self.balances.get(athlete);
+        self.balances.setter(athlete).set(checked_add(balance, U256::from(1))?);
+
+        // Initialize athlete stats
+        let mut stats = self.athlete_stats.setter(token_id);
+        stats.athlete.set(athlete);
+        stats.athlete_name.set_str(&athlete_name);
+        stats.power.set(U256::from(50)); // Default starting stats
+        stats.speed.set(U256::from(50));
+        stats.accuracy.set(U256::from(50));
+        stats.matches_played.set(U256::from(0));
+        stats.total_runs.set(U256::from(0));
+        stats.total_wickets.set(U256::from(0));
+        stats.highest_score.set(U256::from(0));
+        stats.best_bowling.set(U256::from(0));
+        stats.last_updated.set(U256::from(block::timestamp()));
+        stats.is_active.set(true);
+
+        // Map athlete to token
+        self.athlete_to_token.setter(athlete).set(token_id);
+        self.has_profile.setter(athlete).set(true);
+
+        // Increment counters
+        self.next_token_id.set(checked_add(token_id, U256::from(1))?);
+        let total = self.total_minted.get();
+        self.total_minted.set(checked_add(total, U256::from(1))?);
+
+        // Emit events
+        evm::log(Transfer {
+            from: Address::ZERO,
+            to: athlete,
+            tokenId: token_id,
+        });
+
+        evm::log(AthleteProfileMinted {
+            tokenId: token_id,
+            athlete,
+            name: athlete_name,
+        });
+
+        Ok(token_id)
     }
 
-    /// Calculate speed stat (based on strike rate approximation)
-    fn _calculate_speed(&self, stats: &StorageGuard<AthleteStats>) -> Result<U256, Vec<u8>> {
-        // Simplified: higher total runs = better speed
-        let runs = stats.total_runs.get();
-        let speed = runs / U256::from(10); // Rough calculation
+    /// Whether an athlete has a profile, including a legacy token 0 minted
+    /// before the existence flag (where `athlete_to_token` reads 0)
+    fn _has_profile(&self, athlete: Address) -> bool {
+        if self.has_profile.get(athlete) || self.athlete_to_token.get(athlete) > U256::from(0) {
+            return true;
+        }
 
-        Ok(if speed > U256::from(100) {
-            U256::from(100)
-        } else if speed < U256::from(20) {
-            U256::from(50)
-        } else {
-            speed
-        })
+        let legacy = self.athlete_stats.get(U256::from(0));
+        legacy.is_active.get() && legacy.athlete.get() == athlete
     }
 
-    /// Calculate accuracy stat (based on wickets)
-    fn _calculate_accuracy(&self, stats: &StorageGuard<AthleteStats>) -> Result<U256, Vec<u8>> {
-        let matches = stats.matches_played.get();
-        if matches == U256::from(0) {
-            return Ok(U256::from(50));
+    /// Store a season window and track it while it hasn't ended
+    fn _set_season_window(&mut self, season_id: U256, start: U256, end: U256) -> Result<(), Vec<u8>> {
+        let mut window = self.season_windows.setter(season_id);
+        window.start.set(start);
+        window.end.set(end);
+
+        self._prune_open_seasons();
+        if end > U256::from(block::timestamp()) && !self.season_open.get(season_id) {
+            if self.open_seasons.len() >= MAX_OPEN_SEASONS {
+                return Err(TooManyOpenSeasons {}.encode());
+            }
+            self.open_seasons.push(season_id);
+            self.season_open.setter(season_id).set(true);
         }
 
-        let wickets = stats.total_wickets.get();
-        let avg_wickets = wickets / matches;
+        evm::log(SeasonWindowSet {
+            seasonId: season_id,
+            start,
+            end,
+        });
+
+        Ok(())
+    }
+
+    /// Drop ended (or cancelled) windows from the open list
+    fn _prune_open_seasons(&mut self) {
+        let now = U256::from(block::timestamp());
+        let mut i = 0;
+        while i < self.open_seasons.len() {
+            let season_id = self.open_seasons.get(i).unwrap_or_default();
+            if self.season_windows.get(season_id).end.get() > now {
+                i += 1;
+                continue;
+            }
+
+            // Swap-remove
+            let last = self.open_seasons.len() - 1;
+            let last_id = self.open_seasons.get(last).unwrap_or_default();
+            if let Some(mut slot) = self.open_seasons.setter(i) {
+                slot.set(last_id);
+            }
+            self.open_seasons.truncate(last);
+            self.season_open.setter(season_id).set(false);
+        }
+    }
 
-        // Accuracy based on average wickets per match
-        let accuracy = U256::from(50) + (avg_wickets * U256::from(10));
+    /// Whether the current time falls in an open season window
+    fn _is_season_active(&self) -> bool {
+        let now = U256::from(block::timestamp());
+        for i in 0..self.open_seasons.len() {
+            let season_id = self.open_seasons.get(i).unwrap_or_default();
+            let window = self.season_windows.get(season_id);
+            if now >= window.start.get() && now < window.end.get() {
+                return true;
+            }
+        }
+        false
+    }
 
-        Ok(if accuracy > U256::from(100) {
-            U256::from(100)
-        } else {
-            accuracy
+    /// Revert unless the transfer policy currently allows transfers
+    fn _check_transfer_policy(&self) -> Result<(), Vec<u8>> {
+        match self.transfer_mode.get() {
+            TRANSFER_SOULBOUND => Err(SoulboundToken {}.encode()),
+            TRANSFER_SEASON_LOCKED if self._is_season_active() => {
+                Err(TransferLocked {}.encode())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Read one match history entry (zeroed if out of range)
+    fn _match_entry(
+        &self,
+        token_id: U256,
+        index: usize,
+    ) -> (FixedBytes<32>, U256, U256, U256, U256, u8, U256) {
+        let history = self.match_history.get(token_id);
+        match history.get(index) {
+            Some(entry) => (
+                entry.match_id.get(),
+                entry.runs.get(),
+                entry.wickets.get(),
+                entry.balls_faced.get(),
+                entry.balls_bowled.get(),
+                entry.tier.get(),
+                entry.timestamp.get(),
+            ),
+            None => Default::default(),
+        }
+    }
+
+    /// Stat formula weights, falling back to the defaults until configured
+    fn _stat_formula(&self) -> StatFormula {
+        if !self.stat_weights.configured.get() {
+            return StatFormula {
+                decay: U256::from(DEFAULT_DECAY_BPS),
+                power_strike_rate: U256::from(DEFAULT_POWER_STRIKE_RATE_BPS),
+                speed_wearable: U256::from(DEFAULT_SPEED_WEARABLE_BPS),
+                accuracy_economy: U256::from(DEFAULT_ACCURACY_ECONOMY_BPS),
+                accuracy_average: U256::from(DEFAULT_ACCURACY_AVERAGE_BPS),
+            };
+        }
+
+        StatFormula {
+            decay: self.stat_weights.decay_bps.get(),
+            power_strike_rate: self.stat_weights.power_strike_rate_bps.get(),
+            speed_wearable: self.stat_weights.speed_wearable_bps.get(),
+            accuracy_economy: self.stat_weights.accuracy_economy_bps.get(),
+            accuracy_average: self.stat_weights.accuracy_average_bps.get(),
+        }
+    }
+
+    /// Decay every form sum and add the new match
+    fn _update_form(&mut self, token_id: U256, sample: &MatchSample) -> Result<(), Vec<u8>> {
+        let decay = self._stat_formula().decay;
+        let fold = |sum: U256, value: U256| decay_fold(sum, value, decay);
+
+        // Matches without a wearable reading only fade older readings
+        let has_speed = sample.top_speed > U256::from(0);
+        let speed_reading = if has_speed { U256::from(1) } else { U256::from(0) };
+
+        // Same for bowling figures without recorded runs conceded
+        let (bowling_match, wickets_taken, balls_bowled_count, runs_conceded_count) =
+            if sample.bowling_recorded {
+                (U256::from(1), sample.wickets, sample.balls_bowled, sample.runs_conceded)
+            } else {
+                (U256::from(0), U256::from(0), U256::from(0), U256::from(0))
+            };
+
+        let mut form = self.form.setter(token_id);
+        let matches = form.matches.get();
+        form.matches.set(fold(matches, U256::from(1))?);
+        let runs = form.runs.get();
+        form.runs.set(fold(runs, sample.runs)?);
+        let balls_faced = form.balls_faced.get();
+        form.balls_faced.set(fold(balls_faced, sample.balls_faced)?);
+        let bowling_matches = form.bowling_matches.get();
+        form.bowling_matches.set(fold(bowling_matches, bowling_match)?);
+        let wickets = form.wickets.get();
+        form.wickets.set(fold(wickets, wickets_taken)?);
+        let balls_bowled = form.balls_bowled.get();
+        form.balls_bowled.set(fold(balls_bowled, balls_bowled_count)?);
+        let runs_conceded = form.runs_conceded.get();
+        form.runs_conceded.set(fold(runs_conceded, runs_conceded_count)?);
+        let speed_total = form.speed_total.get();
+        form.speed_total.set(fold(speed_total, sample.top_speed)?);
+        let speed_readings = form.speed_readings.get();
+        form.speed_readings.set(fold(speed_readings, speed_reading)?);
+
+        Ok(())
+    }
+
+    /// Derived form metrics (x100) from the decayed sums
+    fn _form_metrics(&self, token_id: U256) -> Result<FormMetrics, Vec<u8>> {
+        let form = self.form.get(token_id);
+
+        let matches = form.matches.get();
+        let runs = form.runs.get();
+        let wickets = form.wickets.get();
+        let balls_bowled = form.balls_bowled.get();
+        let runs_conceded = form.runs_conceded.get();
+
+        Ok(FormMetrics {
+            // (runs / balls) * 100, x100
+            strike_rate: form_ratio(runs, 10_000, form.balls_faced.get())?,
+            runs_per_match: form_ratio(runs, 100, matches)?,
+            // (runs conceded / overs) x100, with overs = balls / 6
+            economy: form_ratio(runs_conceded, 600, balls_bowled)?,
+            bowling_average: form_ratio(runs_conceded, 100, wickets)?,
+            wickets_per_match: form_ratio(wickets, 100, form.bowling_matches.get())?,
+            top_speed: form_ratio(form.speed_total.get(), 1, form.speed_readings.get())?,
         })
     }
+
+    /// Power, Speed and Accuracy (0-100) from the current form
+    fn _calculate_stats(&self, token_id: U256) -> Result<(U256, U256, U256), Vec<u8>> {
+        let formula = self._stat_formula();
+        let metrics = self._form_metrics(token_id)?;
+        let neutral = U256::from(NEUTRAL_STAT);
+
+        let strike_rate_score = match metrics.strike_rate {
+            Some(strike_rate) => Some(range_score(strike_rate, STRIKE_RATE_RANGE)?),
+            None => None,
+        };
+
+        // Power: strike rate & runs per match (needs balls faced)
+        let power = match (strike_rate_score, metrics.runs_per_match) {
+            (Some(strike_rate_score), Some(runs_per_match)) => blend(&[
+                (strike_rate_score, formula.power_strike_rate),
+                (
+                    range_score(runs_per_match, RUNS_PER_MATCH_RANGE)?,
+                    complement(formula.power_strike_rate)?,
+                ),
+            ])?,
+            _ => neutral,
+        };
+
+        // Speed: wearable top speed & strike rate, whichever is available
+        let speed = match (metrics.top_speed, strike_rate_score) {
+            (Some(top_speed), Some(strike_rate_score)) => blend(&[
+                (range_score(top_speed, TOP_SPEED_RANGE)?, formula.speed_wearable),
+                (strike_rate_score, complement(formula.speed_wearable)?),
+            ])?,
+            (Some(top_speed), None) => range_score(top_speed, TOP_SPEED_RANGE)?,
+            (None, Some(strike_rate_score)) => strike_rate_score,
+            (None, None) => neutral,
+        };
+
+        // Accuracy: economy, bowling average & wickets per match (needs balls
+        // bowled in matches with recorded runs conceded); no wickets yet means
+        // the bowling average scores 0
+        let accuracy = match (metrics.economy, metrics.wickets_per_match) {
+            (Some(economy), Some(wickets_per_match)) => {
+                let average_score = match metrics.bowling_average {
+                    Some(average) => range_score(average, BOWLING_AVERAGE_RANGE)?,
+                    None => U256::from(0),
+                };
+                let wickets_weight =
+                    complement(checked_add(formula.accuracy_economy, formula.accuracy_average)?)?;
+                blend(&[
+                    (range_score(economy, ECONOMY_RANGE)?, formula.accuracy_economy),
+                    (average_score, formula.accuracy_average),
+                    (range_score(wickets_per_match, WICKETS_PER_MATCH_RANGE)?, wickets_weight),
+                ])?
+            }
+            _ => neutral,
+        };
+
+        Ok((power, speed, accuracy))
+    }
+}
+
+// ==================== ERC-165 Helpers ====================
+
+/// Whether an ERC-165 interface ID is implemented by AthleteNFT
+pub fn is_supported_interface(id: [u8; 4]) -> bool {
+    id == INTERFACE_ERC165 || id == INTERFACE_ERC721 || id == INTERFACE_ERC721_METADATA
+}
+
+// ==================== Match History Helpers ====================
+
+/// Indices of a history page, clamped to the history length
+pub fn history_page(len: usize, offset: U256, limit: U256) -> Range<usize> {
+    let len = U256::from(len);
+    let start = offset.min(len);
+    let end = start.saturating_add(limit).min(len);
+    start.to::<usize>()..end.to::<usize>()
+}
+
+/// Indices of the last `count` history entries (oldest first)
+pub fn recent_history(len: usize, count: U256) -> Range<usize> {
+    let count = count.min(U256::from(len)).to::<usize>();
+    len - count..len
+}
+
+// ==================== Stat Formula Helpers ====================
+
+/// Decay a form sum by `decay_bps` and add a new value (scaled by 10^6)
+pub fn decay_fold(sum: U256, value: U256, decay_bps: U256) -> Result<U256, Vec<u8>> {
+    checked_add(
+        mul_div(sum, decay_bps, U256::from(STAT_BPS_DENOMINATOR), Rounding::Nearest)?,
+        checked_mul(value, U256::from(FORM_PRECISION))?,
+    )
+}
+
+/// `numerator * scale / denominator` of two decayed sums, or None while
+/// nothing has been recorded
+pub fn form_ratio(numerator: U256, scale: u64, denominator: U256) -> Result<Option<U256>, Vec<u8>> {
+    if denominator == U256::from(0) {
+        return Ok(None);
+    }
+    Ok(Some(mul_div(numerator, U256::from(scale), denominator, Rounding::Nearest)?))
+}
+
+/// Map a metric linearly onto 0-100 between its zero point and full-marks
+/// point (clamped); a full-marks point below the zero point rewards lower values
+pub fn range_score(value: U256, (zero_point, full_marks): (u64, u64)) -> Result<U256, Vec<u8>> {
+    let (zero_point, full_marks) = (U256::from(zero_point), U256::from(full_marks));
+    let hundred = U256::from(100);
+
+    if full_marks > zero_point {
+        if value <= zero_point {
+            return Ok(U256::from(0));
+        }
+        if value >= full_marks {
+            return Ok(hundred);
+        }
+        mul_div(value - zero_point, hundred, full_marks - zero_point, Rounding::Nearest)
+    } else {
+        if value >= zero_point {
+            return Ok(U256::from(0));
+        }
+        if value <= full_marks {
+            return Ok(hundred);
+        }
+        mul_div(zero_point - value, hundred, zero_point - full_marks, Rounding::Nearest)
+    }
+}
+
+/// Remaining share of a bps weight
+fn complement(weight_bps: U256) -> Result<U256, Vec<u8>> {
+    checked_sub(U256::from(STAT_BPS_DENOMINATOR), weight_bps)
+}
+
+/// Weighted average of 0-100 scores; weights are bps summing to 10,000
+pub fn blend(parts: &[(U256, U256)]) -> Result<U256, Vec<u8>> {
+    let mut total = U256::from(0);
+    for (score, weight) in parts {
+        total = checked_add(total, checked_mul(*score, *weight)?)?;
+    }
+    mul_div(total, U256::from(1), U256::from(STAT_BPS_DENOMINATOR), Rounding::Nearest)
 }
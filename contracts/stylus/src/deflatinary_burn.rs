@@ -5,9 +5,29 @@
 //! a percentage of tokens to create deflationary pressure.
 //!
 //! ## Burn Mechanics:
-//! - Performance-based burn multipliers (1.5x to 4.0x)
-//! - Effort validation from wearable data
-//! - 10% of rewards are burned to reduce supply
+//! - Performance-based burn multipliers (1.5x to 4.0x), read from RewardTiers
+//! - Rewards use the tier config version the oracle pinned at match registration
+//! - Effort validation from wearable data, mapped through a configurable
+//!   piecewise curve (threshold, floor, knee points, cap) in integer math
+//! - A configurable share of rewards (default 10%, overridable per tier) is
+//!   withheld; part of it can go to a treasury and a community pool, the
+//!   rest is burned to reduce supply
+//! - Rewards are paid out of this contract's SPP balance (the reward pool).
+//!   Settling moves tokens: callers that relied on `burn_for_performance`
+//!   only recording amounts must fund the pool first, or settlement reverts
+//! - Alternatively rewards are minted on demand through SPPToken's MINTER
+//!   role, bounded by the token's supply cap and minter allowance
+//! - Emission is bounded per epoch, per match and per player per epoch
+//! - Merkle-distributor mode: a settler posts a root of (player, amount)
+//!   leaves and players pull their reward with a proof; the withheld share
+//!   is taken at claim time. Roots can expire after a claim window; the
+//!   unclaimed reservation is released on expiry or reversal
+//! - Optional per-tier vesting: the player's share is locked in a
+//!   RewardVesting grant instead of being paid out liquid
+//! - Clawback: rewards of overturned matches can be reversed, recovering
+//!   unvested and unclaimed tokens back into the pool
+//! - All amounts are SPPToken base units (18 decimals); ratios go through
+//!   the shared `fixed_point::mul_div` with explicit rounding
 //! - Tracks total burned for transparency
 
 use stylus_sdk::{
@@ -15,18 +35,44 @@ use stylus_sdk::{
     prelude::*,
     msg,
     block,
+    contract,
     call::Call,
 };
 
-// Reward tiers matching the NestJS implementation
-const TIER_NIFTY_FIFTY: u8 = 0;
-const TIER_GAYLE_STORM: u8 = 1;
-const TIER_FIVE_WICKET_HAUL: u8 = 2;
-const TIER_HAT_TRICK: u8 = 3;
-const TIER_MAIDEN_MASTER: u8 = 4;
-const TIER_RUN_MACHINE: u8 = 5;
-const TIER_GOLDEN_ARM: u8 = 6;
-const TIER_ALL_ROUNDER: u8 = 7;
+use crate::fixed_point::{checked_add, checked_mul, checked_sub, mul_div, Rounding};
+
+/// Denominator for all basis-point values (100% = 10,000 bps)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Default share of each reward withheld from the player (10%)
+pub const DEFAULT_BURN_BPS: u64 = 1_000;
+
+/// Default emission epoch length (1 day)
+pub const DEFAULT_EPOCH_LENGTH: u64 = 24 * 60 * 60;
+
+// Tier economics live in the RewardTiers contract; this is the subset we read
+sol_interface! {
+    interface IRewardTiers {
+        function getTierConfig(uint8 tier_id) external view returns (uint256, uint256, bool);
+        function getTierAtVersion(uint8 tier_id, uint256 version) external view returns (uint256, uint256, bool);
+    }
+
+    interface IPerformanceOracle {
+        function getMatchConfigVersion(bytes32 match_id) external view returns (uint256);
+        function isMatchOverturned(bytes32 match_id) external view returns (bool);
+    }
+
+    interface ISPPToken {
+        function transfer(address to, uint256 amount) external returns (bool);
+        function burn(uint256 amount) external;
+        function mint(address to, uint256 amount) external;
+    }
+
+    interface IRewardVesting {
+        function createGrant(address beneficiary, uint256 amount, uint256 cliff, uint256 duration, bool linear) external returns (uint256);
+        function revoke(uint256 grant_id, address recipient) external returns (uint256);
+    }
+}
 
 sol_storage! {
     /// Main DeflatinaryBurn contract storage
@@ -41,11 +87,8 @@ sol_storage! {
         /// Performance Oracle contract address
         address oracle_contract;
 
-        /// Burn multipliers for each tier (multiplied by 10 to avoid decimals)
-        mapping(uint8 => uint256) burn_multipliers;
-
-        /// Base reward amounts for each tier
-        mapping(uint8 => uint256) base_rewards;
+        /// RewardTiers contract address (source of multipliers and base rewards)
+        address reward_tiers_contract;
 
         /// Total tokens burned across all transactions
         uint256 total_burned;
@@ -61,6 +104,119 @@ sol_storage! {
 
         /// Burn transaction records
         mapping(bytes32 => BurnTransaction) burn_transactions;
+
+        /// Share of each reward withheld from the player, in bps
+        uint256 default_burn_bps;
+
+        /// Per-tier overrides of the withheld share, in bps
+        mapping(uint8 => uint256) tier_burn_bps;
+
+        /// Whether a tier uses its override instead of the default
+        mapping(uint8 => bool) tier_burn_bps_set;
+
+        /// Treasury receiving part of the withheld share
+        address treasury;
+
+        /// Treasury's cut of the withheld share, in bps
+        uint256 treasury_share_bps;
+
+        /// Community pool receiving part of the withheld share
+        address community_pool;
+
+        /// Community pool's cut of the withheld share, in bps
+        uint256 community_share_bps;
+
+        /// Total sent to the treasury
+        uint256 total_to_treasury;
+
+        /// Total sent to the community pool
+        uint256 total_to_community;
+
+        /// Length of an emission epoch in seconds
+        uint256 epoch_length;
+
+        /// Max gross rewards per epoch (0 = unlimited)
+        uint256 epoch_budget;
+
+        /// Max gross rewards per match (0 = unlimited)
+        uint256 match_budget;
+
+        /// Max gross rewards per player per epoch (0 = unlimited)
+        uint256 player_epoch_cap;
+
+        /// Gross rewards emitted per epoch
+        mapping(uint256 => uint256) epoch_emitted;
+
+        /// Gross rewards emitted per match
+        mapping(bytes32 => uint256) match_emitted;
+
+        /// Gross rewards emitted per epoch per player
+        mapping(uint256 => mapping(address => uint256)) player_epoch_emitted;
+
+        /// Address allowed to post Merkle reward roots (besides the owner)
+        address settler;
+
+        /// Posted Merkle reward roots by root ID (a match ID or an epoch key)
+        mapping(bytes32 => RewardRoot) reward_roots;
+
+        /// Claimed leaf bitmaps: root ID => word index => bits
+        mapping(bytes32 => mapping(uint256 => uint256)) claimed_bitmaps;
+
+        /// Seconds a root stays claimable (0 = roots never expire)
+        uint256 root_claim_window;
+
+        /// Mint each reward through SPPToken's MINTER role instead of paying
+        /// it from the pre-funded pool balance
+        bool mint_rewards;
+
+        /// RewardVesting contract address (zero = vesting disabled)
+        address vesting_contract;
+
+        /// Vesting schedule per tier
+        mapping(uint8 => TierVesting) tier_vesting;
+
+        /// Arbiter allowed to reverse rewards (besides the owner)
+        address arbiter;
+
+        /// Total rewards reversed by clawbacks
+        uint256 total_rewards_reversed;
+
+        /// Effort below this score earns nothing
+        uint256 effort_min_threshold;
+
+        /// Minimum effort factor (bps) once the threshold is met
+        uint256 effort_floor_bps;
+
+        /// Effort above this score is treated as the cap (saturation)
+        uint256 effort_cap;
+
+        /// Curve knee points: effort scores (strictly increasing, 0-100)
+        uint256[] effort_knee_x;
+
+        /// Curve knee points: effort factor in bps at each score
+        uint256[] effort_knee_y;
+    }
+
+    /// Vesting schedule applied to a tier's rewards
+    pub struct TierVesting {
+        bool enabled;
+        uint256 cliff; // Seconds
+        uint256 duration; // Seconds (linear schedules)
+        bool linear;
+    }
+
+    /// Merkle root of (index, player, tier, amount) leaves
+    pub struct RewardRoot {
+        bytes32 merkle_root;
+        uint256 total_amount;
+        uint256 claimed_amount;
+        uint256 posted_at;
+        uint256 epoch; // Epoch the reservation was counted in
+        uint256 expires_at; // 0 = never
+        bool is_match_root;
+        bool reversed;
+        bool released; // Unclaimed reservation returned to the budgets
+        bool exists;
     }
 
     /// Burn transaction record
@@ -68,11 +224,18 @@ sol_storage! {
         bytes32 match_id;
         address player;
         uint256 burn_amount;
+        uint256 treasury_amount;
+        uint256 community_amount;
         uint256 reward_amount;
         uint8 tier;
         uint256 effort_score;
         uint256 timestamp;
+        uint256 config_version; // RewardTiers config version used for the reward
+        bool merkle_claim; // Paid via a Merkle claim (no effort score / version)
+        uint256 vesting_grant_id; // RewardVesting grant holding the player share (0 = liquid)
         bool executed;
+        bool reversed;
+        uint256 recovered_amount; // Tokens returned to the pool on reversal
     }
 }
 
@@ -81,10 +244,94 @@ sol! {
         bytes32 indexed matchId,
         address indexed player,
         uint256 burnAmount,
+        uint256 treasuryAmount,
+        uint256 communityAmount,
         uint256 rewardAmount,
         uint8 tier
     );
 
+    event RewardCapped(
+        bytes32 indexed matchId,
+        address indexed player,
+        uint256 requestedAmount,
+        uint256 grantedAmount
+    );
+
+    event RewardRootPosted(
+        bytes32 indexed rootId,
+        bytes32 merkleRoot,
+        uint256 totalAmount
+    );
+
+    event RewardClaimed(
+        bytes32 indexed rootId,
+        uint256 index,
+        address indexed player,
+        uint256 amount
+    );
+
+    event SettlerUpdated(address indexed settler);
+
+    event RootClaimWindowUpdated(uint256 window);
+
+    event RewardRootReleased(
+        bytes32 indexed rootId,
+        uint256 releasedAmount
+    );
+
+    event RewardReversed(
+        bytes32 indexed matchId,
+        address indexed player,
+        uint256 rewardAmount,
+        uint256 recoveredAmount
+    );
+
+    event RewardRootReversed(
+        bytes32 indexed rootId,
+        uint256 unclaimedAmount
+    );
+
+    event ArbiterUpdated(address indexed arbiter);
+
+    event EffortCurveUpdated(
+        uint256 minThreshold,
+        uint256 floorBps,
+        uint256 cap,
+        uint256 kneeCount
+    );
+
+    event RewardFundingUpdated(bool mintRewards);
+
+    event VestingContractUpdated(address indexed vestingContract);
+
+    event TierVestingUpdated(
+        uint8 indexed tier,
+        bool enabled,
+        uint256 cliff,
+        uint256 duration,
+        bool linear
+    );
+
+    event EmissionBudgetsUpdated(
+        uint256 epochLength,
+        uint256 epochBudget,
+        uint256 matchBudget,
+        uint256 playerEpochCap
+    );
+
+    event BurnRateUpdated(
+        uint8 indexed tier,
+        bool isTierOverride,
+        uint256 burnBps
+    );
+
+    event SplitPolicyUpdated(
+        address indexed treasury,
+        uint256 treasuryShareBps,
+        address indexed communityPool,
+        uint256 communityShareBps
+    );
+
     event RewardCalculated(
         address indexed player,
         uint8 tier,
@@ -93,59 +340,105 @@ sol! {
         uint256 finalReward
     );
 
-    event TierUpdated(
-        uint8 indexed tier,
-        uint256 multiplier,
-        uint256 baseReward
+    event RewardTiersContractUpdated(
+        address indexed previousContract,
+        address indexed newContract
     );
 
     error InvalidTier();
+    error TierNotActive();
+    error InvalidAddress();
     error InvalidEffortScore();
     error OracleVerificationFailed();
     error TokenTransferFailed();
     error Unauthorized();
     error BurnAlreadyExecuted();
+    error InvalidBps();
+    error InvalidEpochLength();
+    error ArrayLengthMismatch();
+    error RootAlreadyPosted();
+    error RootNotFound();
+    error AlreadyClaimed();
+    error InvalidProof();
+    error BudgetExceeded();
+    error MatchOverturned();
+    error MatchNotOverturned();
+    error BurnNotExecuted();
+    error AlreadyReversed();
+    error InvalidEffortCurve();
+    error RootExpired();
+    error RootNotExpired();
+    error RootAlreadyReleased();
+    error InvalidSchedule();
 }
 
 #[public]
 impl DeflatinaryBurn {
-    /// Initialize the contract with token and oracle addresses
+    /// Initialize the contract with token, oracle and reward tiers addresses
     pub fn init(
         &mut self,
         token_contract: Address,
         oracle_contract: Address,
+        reward_tiers_contract: Address,
     ) -> Result<(), Vec<u8>> {
+        if reward_tiers_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
         let caller = msg::sender();
         self.owner.set(caller);
         self.token_contract.set(token_contract);
         self.oracle_contract.set(oracle_contract);
+        self.reward_tiers_contract.set(reward_tiers_contract);
         self.total_burned.set(U256::from(0));
         self.total_rewards_distributed.set(U256::from(0));
+        self.default_burn_bps.set(U256::from(DEFAULT_BURN_BPS));
+        self.epoch_length.set(U256::from(DEFAULT_EPOCH_LENGTH));
+
+        // Default curve is linear: factor = effort / 100
+        self.effort_min_threshold.set(U256::from(0));
+        self.effort_floor_bps.set(U256::from(0));
+        self.effort_cap.set(U256::from(100));
+        self.effort_knee_x.push(U256::from(0));
+        self.effort_knee_y.push(U256::from(0));
+        self.effort_knee_x.push(U256::from(100));
+        self.effort_knee_y.push(U256::from(BPS_DENOMINATOR));
+
+        Ok(())
+    }
+
+    /// Point the contract at a different RewardTiers deployment (admin only)
+    pub fn set_reward_tiers_contract(
+        &mut self,
+        reward_tiers_contract: Address,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if reward_tiers_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        let previous = self.reward_tiers_contract.get();
+        self.reward_tiers_contract.set(reward_tiers_contract);
 
-        // Initialize burn multipliers (multiplied by 10)
-        self.burn_multipliers.setter(TIER_NIFTY_FIFTY).set(U256::from(15)); // 1.5x
-        self.burn_multipliers.setter(TIER_GAYLE_STORM).set(U256::from(30)); // 3.0x
-        self.burn_multipliers.setter(TIER_FIVE_WICKET_HAUL).set(U256::from(25)); // 2.5x
-        self.burn_multipliers.setter(TIER_HAT_TRICK).set(U256::from(30)); // 3.0x
-        self.burn_multipliers.setter(TIER_MAIDEN_MASTER).set(U256::from(15)); // 1.5x
-        self.burn_multipliers.setter(TIER_RUN_MACHINE).set(U256::from(40)); // 4.0x
-        self.burn_multipliers.setter(TIER_GOLDEN_ARM).set(U256::from(13)); // 1.3x
-        self.burn_multipliers.setter(TIER_ALL_ROUNDER).set(U256::from(20)); // 2.0x
-
-        // Initialize base rewards
-        self.base_rewards.setter(TIER_NIFTY_FIFTY).set(U256::from(50));
-        self.base_rewards.setter(TIER_GAYLE_STORM).set(U256::from(150));
-        self.base_rewards.setter(TIER_FIVE_WICKET_HAUL).set(U256::from(100));
-        self.base_rewards.setter(TIER_HAT_TRICK).set(U256::from(200));
-        self.base_rewards.setter(TIER_MAIDEN_MASTER).set(U256::from(30));
-        self.base_rewards.setter(TIER_RUN_MACHINE).set(U256::from(250));
-        self.base_rewards.setter(TIER_GOLDEN_ARM).set(U256::from(40));
-        self.base_rewards.setter(TIER_ALL_ROUNDER).set(U256::from(120));
+        evm::log(RewardTiersContractUpdated {
+            previousContract: previous,
+            newContract: reward_tiers_contract,
+        });
 
         Ok(())
     }
 
-    /// Calculate reward based on tier and effort score
+    /// Get the configured RewardTiers contract address
+    pub fn reward_tiers_contract(&self) -> Result<Address, Vec<u8>> {
+        Ok(self.reward_tiers_contract.get())
+    }
+
+    /// Calculate reward based on tier and effort score at the config currently
+    /// in force. Matches pay at the version pinned at their registration; use
+    /// the overload taking a match ID to preview an actual payout.
     /// @param tier Performance tier (0-7)
     /// @param effortScore Effort score from wearable (0-100)
     /// @return Final reward amount after applying multipliers
@@ -154,36 +447,173 @@ impl DeflatinaryBurn {
         tier: u8,
         effort_score: U256,
     ) -> Result<U256, Vec<u8>> {
-        // Validate tier
-        if tier > TIER_ALL_ROUNDER {
-            return Err(InvalidTier {}.encode());
+        // Validate effort score (must be 0-100)
+        if effort_score > U256::from(100) {
+            return Err(InvalidEffortScore {}.encode());
         }
 
-        // Validate effort score (must be 0-100)
+        // Get base reward and multiplier currently in force (validates the tier)
+        let (burn_multiplier, base_reward) = self._active_tier_config(tier)?;
+
+        self._apply_reward_formula(base_reward, burn_multiplier, effort_score)
+    }
+
+    /// Calculate the reward for a match at the tier config version pinned at
+    /// its registration (what a payout grants before budget caps)
+    /// @param matchId The match identifier
+    /// @param tier Performance tier (0-7)
+    /// @param effortScore Effort score from wearable (0-100)
+    /// @return Final reward amount after applying multipliers
+    #[selector(name = "calculateReward")]
+    pub fn calculate_match_reward(
+        &self,
+        match_id: FixedBytes<32>,
+        tier: u8,
+        effort_score: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let config_version = self._match_config_version(match_id)?;
+        let (reward, _) = self._compute_reward(tier, effort_score, config_version)?;
+        Ok(reward)
+    }
+
+    /// Preview every component of a match payout, at the tier config version
+    /// pinned at the match's registration
+    /// @param matchId The match identifier
+    /// @param tier Performance tier (0-7)
+    /// @param effortScore Effort score from wearable (0-100)
+    /// @return (multiplier, baseReward, effortFactorBps, grossReward,
+    ///          burnAmount, treasuryAmount, communityAmount, playerAmount)
+    pub fn preview_reward(
+        &self,
+        match_id: FixedBytes<32>,
+        tier: u8,
+        effort_score: U256,
+    ) -> Result<(U256, U256, U256, U256, U256, U256, U256, U256), Vec<u8>> {
         if effort_score > U256::from(100) {
             return Err(InvalidEffortScore {}.encode());
         }
 
-        // Get base reward and multiplier
-        let base_reward = self.base_rewards.get(tier);
-        let burn_multiplier = self.burn_multipliers.get(tier);
+        let config_version = self._match_config_version(match_id)?;
+        let (multiplier, base_reward) =
+            self._active_tier_config_at_version(tier, config_version)?;
+        let effort_factor = self._effort_factor(effort_score);
+        let gross = self._apply_reward_formula(base_reward, multiplier, effort_score)?;
+        let (burn_amount, treasury_amount, community_amount) = self._split_reward(tier, gross)?;
+        let player_amount = checked_sub(
+            gross,
+            checked_add(checked_add(burn_amount, treasury_amount)?, community_amount)?,
+        )?;
+
+        Ok((
+            multiplier,
+            base_reward,
+            effort_factor,
+            gross,
+            burn_amount,
+            treasury_amount,
+            community_amount,
+            player_amount,
+        ))
+    }
+
+    /// Configure the effort curve (admin only)
+    /// factor(e) = 0 if e < minThreshold, otherwise
+    /// max(floorBps, interpolate(min(e, cap))) where interpolate is linear
+    /// between knee points and flat outside them.
+    /// @param minThreshold Effort below this earns nothing
+    /// @param floorBps Minimum factor once the threshold is met
+    /// @param cap Effort saturation point (0-100)
+    /// @param kneeX Effort scores of the knee points (strictly increasing, 0-100)
+    /// @param kneeY Factor in bps at each knee point (0-10000)
+    pub fn set_effort_curve(
+        &mut self,
+        min_threshold: U256,
+        floor_bps: U256,
+        cap: U256,
+        knee_x: Vec<U256>,
+        knee_y: Vec<U256>,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let max_effort = U256::from(100);
+        let bps = U256::from(BPS_DENOMINATOR);
+        if knee_x.is_empty()
+            || knee_x.len() != knee_y.len()
+            || cap > max_effort
+            || min_threshold > max_effort
+            || floor_bps > bps
+        {
+            return Err(InvalidEffortCurve {}.encode());
+        }
+
+        for i in 0..knee_x.len() {
+            if knee_x[i] > max_effort || knee_y[i] > bps || (i > 0 && knee_x[i] <= knee_x[i - 1]) {
+                return Err(InvalidEffortCurve {}.encode());
+            }
+        }
+
+        self.effort_min_threshold.set(min_threshold);
+        self.effort_floor_bps.set(floor_bps);
+        self.effort_cap.set(cap);
+
+        self.effort_knee_x.truncate(0);
+        self.effort_knee_y.truncate(0);
+        for i in 0..knee_x.len() {
+            self.effort_knee_x.push(knee_x[i]);
+            self.effort_knee_y.push(knee_y[i]);
+        }
+
+        evm::log(EffortCurveUpdated {
+            minThreshold: min_threshold,
+            floorBps: floor_bps,
+            cap,
+            kneeCount: U256::from(knee_x.len()),
+        });
+
+        Ok(())
+    }
+
+    /// Get the effort curve
+    /// @return (minThreshold, floorBps, cap, kneeX, kneeY)
+    pub fn get_effort_curve(&self) -> Result<(U256, U256, U256, Vec<U256>, Vec<U256>), Vec<u8>> {
+        let mut knee_x = Vec::with_capacity(self.effort_knee_x.len());
+        let mut knee_y = Vec::with_capacity(self.effort_knee_y.len());
+        for i in 0..self.effort_knee_x.len() {
+            knee_x.push(self.effort_knee_x.get(i).unwrap_or_default());
+            knee_y.push(self.effort_knee_y.get(i).unwrap_or_default());
+        }
 
-        // Calculate effort multiplier (effort_score / 100)
-        // Multiply first to avoid precision loss
-        let effort_adjusted = (base_reward * effort_score) / U256::from(100);
+        Ok((
+            self.effort_min_threshold.get(),
+            self.effort_floor_bps.get(),
+            self.effort_cap.get(),
+            knee_x,
+            knee_y,
+        ))
+    }
 
-        // Apply burn multiplier (divided by 10 since we stored multiplied values)
-        let final_reward = (effort_adjusted * burn_multiplier) / U256::from(10);
+    /// Get the effort factor (bps) the curve yields for an effort score
+    pub fn effort_factor(&self, effort_score: U256) -> Result<U256, Vec<u8>> {
+        if effort_score > U256::from(100) {
+            return Err(InvalidEffortScore {}.encode());
+        }
 
-        Ok(final_reward)
+        Ok(self._effort_factor(effort_score))
     }
 
     /// Execute burn for a player's performance
+    /// Pays the player (or a vesting grant), treasury and community pool and
+    /// burns the rest out of the reward pool; it no longer only records amounts.
+    /// The reward is capped by the remaining match, epoch and player budgets;
+    /// reverts with `BudgetExceeded` when nothing is left, so the burn can be
+    /// retried once budget frees up (e.g. next epoch).
     /// @param matchId The match identifier
     /// @param player Player's address
     /// @param tier Performance tier
     /// @param effortScore Effort score from wearable
-    /// @return Burn amount and reward amount
+    /// @return Burn amount (tokens destroyed) and gross reward amount
     pub fn burn_for_performance(
         &mut self,
         match_id: FixedBytes<32>,
@@ -197,144 +627,1406 @@ impl DeflatinaryBurn {
             return Err(Unauthorized {}.encode());
         }
 
-        // Create transaction ID
-        let tx_id = self.compute_tx_id(match_id, player);
-
-        // Check if already executed
-        let existing_tx = self.burn_transactions.get(tx_id);
-        if existing_tx.executed.get() {
-            return Err(BurnAlreadyExecuted {}.encode());
-        }
-
-        // Calculate reward
-        let reward_amount = self.calculate_reward(tier, effort_score)?;
+        let config_version = self._match_config_version(match_id)?;
+        let (requested, base_reward) =
+            self._compute_reward(tier, effort_score, config_version)?;
 
-        // Calculate burn amount (10% of reward)
-        let burn_amount = reward_amount / U256::from(10);
+        // Cap by what is left of the match and epoch budgets
+        let available = self._remaining_match_budget(match_id)
+            .min(self._remaining_epoch_budget());
 
-        // Record transaction
-        let mut tx = self.burn_transactions.setter(tx_id);
-        tx.match_id.set(match_id);
-        tx.player.set(player);
-        tx.burn_amount.set(burn_amount);
-        tx.reward_amount.set(reward_amount);
-        tx.tier.set(tier);
-        tx.effort_score.set(effort_score);
-        tx.timestamp.set(U256::from(block::timestamp()));
-        tx.executed.set(true);
+        self._settle(
+            match_id,
+            player,
+            tier,
+            effort_score,
+            config_version,
+            base_reward,
+            requested,
+            requested.min(available),
+        )
+    }
 
-        // Update totals
-        let current_burned = self.total_burned.get();
-        self.total_burned.set(current_burned + burn_amount);
+    /// Execute burns for several players of the same match at once.
+    /// If the combined rewards exceed the remaining match or epoch budget,
+    /// every reward is scaled down pro-rata before per-player caps apply.
+    /// Players left with nothing are skipped (not marked executed) so they
+    /// can be settled later with `burn_for_performance`.
+    /// @param matchId The match identifier
+    /// @param players Player addresses
+    /// @param tiers Performance tier per player
+    /// @param effortScores Effort score per player
+    /// @return Total burn amount and total gross reward amount
+    pub fn burn_for_match(
+        &mut self,
+        match_id: FixedBytes<32>,
+        players: Vec<Address>,
+        tiers: Vec<u8>,
+        effort_scores: Vec<U256>,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.oracle_contract.get() && caller != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
 
-        let current_rewards = self.total_rewards_distributed.get();
-        self.total_rewards_distributed.set(current_rewards + reward_amount);
+        if players.len() != tiers.len() || players.len() != effort_scores.len() {
+            return Err(ArrayLengthMismatch {}.encode());
+        }
 
-        // Update player totals
-        let player_rewards = self.player_total_rewards.get(player);
-        self.player_total_rewards.setter(player).set(player_rewards + reward_amount);
+        let config_version = self._match_config_version(match_id)?;
 
-        let player_burned = self.player_total_burned.get(player);
-        self.player_total_burned.setter(player).set(player_burned + burn_amount);
+        // First pass: gross rewards at the pinned config
+        let mut requested = Vec::with_capacity(players.len());
+        let mut base_rewards = Vec::with_capacity(players.len());
+        let mut total_requested = U256::from(0);
+        for i in 0..players.len() {
+            let (reward, base_reward) =
+                self._compute_reward(tiers[i], effort_scores[i], config_version)?;
+            total_requested = checked_add(total_requested, reward)?;
+            requested.push(reward);
+            base_rewards.push(base_reward);
+        }
 
-        // Emit events
-        evm::log(RewardCalculated {
-            player,
-            tier,
-            baseReward: self.base_rewards.get(tier),
-            effortMultiplier: effort_score,
-            finalReward: reward_amount,
-        });
+        let available = self._remaining_match_budget(match_id)
+            .min(self._remaining_epoch_budget());
 
-        evm::log(TokensBurned {
-            matchId: match_id,
-            player,
-            burnAmount: burn_amount,
-            rewardAmount: reward_amount,
-            tier,
-        });
+        // Second pass: scale pro-rata if needed, then settle each player
+        let mut total_burned = U256::from(0);
+        let mut total_rewarded = U256::from(0);
+        for i in 0..players.len() {
+            let scaled = scale_pro_rata(requested[i], total_requested, available)?;
 
-        Ok((burn_amount, reward_amount))
-    }
+            if requested[i] > U256::from(0)
+                && scaled.min(self._remaining_player_allowance(players[i])) == U256::from(0)
+            {
+                evm::log(RewardCapped {
+                    matchId: match_id,
+                    player: players[i],
+                    requestedAmount: requested[i],
+                    grantedAmount: U256::from(0),
+                });
+                continue;
+            }
 
-    /// Get reward tier multiplier
-    /// @param tier The tier (0-7)
-    /// @return Multiplier (multiplied by 10)
-    pub fn get_reward_tier(&self, tier: u8) -> Result<(U256, U256), Vec<u8>> {
-        if tier > TIER_ALL_ROUNDER {
-            return Err(InvalidTier {}.encode());
+            let (burned, rewarded) = self._settle(
+                match_id,
+                players[i],
+                tiers[i],
+                effort_scores[i],
+                config_version,
+                base_rewards[i],
+                requested[i],
+                scaled,
+            )?;
+            total_burned = checked_add(total_burned, burned)?;
+            total_rewarded = checked_add(total_rewarded, rewarded)?;
         }
 
-        Ok((
-            self.burn_multipliers.get(tier),
-            self.base_rewards.get(tier),
-        ))
+        Ok((total_burned, total_rewarded))
     }
 
-    /// Get total tokens burned
-    pub fn total_burned(&self) -> Result<U256, Vec<u8>> {
-        Ok(self.total_burned.get())
-    }
+    // ==================== Merkle Distributor ====================
 
-    /// Get total rewards distributed
-    pub fn total_rewards(&self) -> Result<U256, Vec<u8>> {
-        Ok(self.total_rewards_distributed.get())
-    }
+    /// Set the settler allowed to post reward roots (admin only)
+    pub fn set_settler(&mut self, settler: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
 
-    /// Get player's total rewards earned
-    pub fn get_player_rewards(&self, player: Address) -> Result<U256, Vec<u8>> {
-        Ok(self.player_total_rewards.get(player))
-    }
+        self.settler.set(settler);
 
-    /// Get player's total tokens burned
-    pub fn get_player_burned(&self, player: Address) -> Result<U256, Vec<u8>> {
-        Ok(self.player_total_burned.get(player))
+        evm::log(SettlerUpdated { settler });
+
+        Ok(())
     }
 
-    /// Update tier configuration (admin only)
-    pub fn update_tier(
+    /// Post a Merkle root of rewards for a match or an epoch (settler or owner).
+    /// Leaves are keccak256(abi.encodePacked(uint256 index, address player,
+    /// uint8 tier, uint256 amount)) with `amount` the gross reward; pairs are
+    /// hashed in sorted order. The total is reserved against the epoch budget
+    /// and, for match roots, the match budget.
+    /// @param rootId Match ID, or an off-chain chosen epoch key
+    /// @param merkleRoot Root of the reward tree
+    /// @param totalAmount Sum of all leaf amounts
+    /// @param isMatchRoot Whether rootId is an oracle match ID
+    pub fn post_reward_root(
         &mut self,
-        tier: u8,
-        multiplier: U256,
-        base_reward: U256,
+        root_id: FixedBytes<32>,
+        merkle_root: FixedBytes<32>,
+        total_amount: U256,
+        is_match_root: bool,
     ) -> Result<(), Vec<u8>> {
-        if msg::sender() != self.owner.get() {
+        let caller = msg::sender();
+        if caller != self.settler.get() && caller != self.owner.get() {
             return Err(Unauthorized {}.encode());
         }
 
-        if tier > TIER_ALL_ROUNDER {
-            return Err(InvalidTier {}.encode());
+        if self.reward_roots.get(root_id).exists.get() {
+            return Err(RootAlreadyPosted {}.encode());
         }
 
-        self.burn_multipliers.setter(tier).set(multiplier);
-        self.base_rewards.setter(tier).set(base_reward);
+        let mut available = self._remaining_epoch_budget();
+        if is_match_root {
+            // Fails for matches the oracle does not know
+            self._match_config_version(root_id)?;
+            available = available.min(self._remaining_match_budget(root_id));
+        }
 
-        evm::log(TierUpdated {
-            tier,
-            multiplier,
-            baseReward: base_reward,
+        if total_amount > available {
+            return Err(BudgetExceeded {}.encode());
+        }
+
+        let now = U256::from(block::timestamp());
+        let window = self.root_claim_window.get();
+        let expires_at = if window == U256::from(0) {
+            U256::from(0)
+        } else {
+            checked_add(now, window)?
+        };
+        let epoch = self._current_epoch();
+
+        let mut root = self.reward_roots.setter(root_id);
+        root.merkle_root.set(merkle_root);
+        root.total_amount.set(total_amount);
+        root.claimed_amount.set(U256::from(0));
+        root.posted_at.set(now);
+        root.epoch.set(epoch);
+        root.expires_at.set(expires_at);
+        root.is_match_root.set(is_match_root);
+        root.reversed.set(false);
+        root.released.set(false);
+        root.exists.set(true);
+
+        // Reserve the whole root against the budgets up front
+        let epoch_total = self.epoch_emitted.get(epoch);
+        self.epoch_emitted.setter(epoch).set(checked_add(epoch_total, total_amount)?);
+
+        if is_match_root {
+            let match_total = self.match_emitted.get(root_id);
+            self.match_emitted.setter(root_id).set(checked_add(match_total, total_amount)?);
+        }
+
+        evm::log(RewardRootPosted {
+            rootId: root_id,
+            merkleRoot: merkle_root,
+            totalAmount: total_amount,
         });
 
         Ok(())
     }
 
-    /// Get burn transaction details
-    pub fn get_burn_transaction(
-        &self,
-        match_id: FixedBytes<32>,
-        player: Address,
+    /// Claim a reward from a posted root. The caller must be the leaf's player.
+    /// Counts against the per-player epoch cap.
+    /// @param rootId Root identifier
+    /// @param index Leaf index
+    /// @param tier Tier recorded in the leaf (selects the withheld share)
+    /// @param amount Gross reward recorded in the leaf
+    /// @param proof Merkle proof for the leaf
+    /// @return Burn amount (tokens destroyed) and gross reward amount
+    pub fn claim(
+        &mut self,
+        root_id: FixedBytes<32>,
+        index: U256,
+        tier: u8,
+        amount: U256,
+        proof: Vec<FixedBytes<32>>,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        let player = msg::sender();
+
+        let (merkle_root, claimed_amount, total_amount) = {
+            let root = self.reward_roots.get(root_id);
+            if !root.exists.get() {
+                return Err(RootNotFound {}.encode());
+            }
+            if root.reversed.get() {
+                return Err(AlreadyReversed {}.encode());
+            }
+            if Self::_root_expired(root.expires_at.get()) {
+                return Err(RootExpired {}.encode());
+            }
+            (root.merkle_root.get(), root.claimed_amount.get(), root.total_amount.get())
+        };
+
+        if self._is_claimed(root_id, index) {
+            return Err(AlreadyClaimed {}.encode());
+        }
+
+        let leaf = Self::_reward_leaf(index, player, tier, amount);
+        if !Self::_verify_proof(&proof, merkle_root, leaf) {
+            return Err(InvalidProof {}.encode());
+        }
+
+        // Guards against a malformed tree whose leaves exceed the posted total
+        if checked_add(claimed_amount, amount)? > total_amount {
+            return Err(BudgetExceeded {}.encode());
+        }
+
+        // Per-player epoch cap; the leaf stays claimable in a later epoch
+        if amount > self._remaining_player_allowance(player) {
+            return Err(BudgetExceeded {}.encode());
+        }
+
+        // Epoch and match budgets were reserved when the root was posted
+        let epoch = self._current_epoch();
+        let player_total = self.player_epoch_emitted.get(epoch).get(player);
+        self.player_epoch_emitted
+            .setter(epoch)
+            .setter(player)
+            .set(checked_add(player_total, amount)?);
+
+        self._set_claimed(root_id, index);
+        self.reward_roots
+            .setter(root_id)
+            .claimed_amount
+            .set(checked_add(claimed_amount, amount)?);
+
+        let result = self._record_reward(
+            root_id,
+            player,
+            tier,
+            U256::from(0),
+            U256::from(0),
+            U256::from(0),
+            amount,
+            Some(index),
+        )?;
+
+        evm::log(RewardClaimed {
+            rootId: root_id,
+            index,
+            player,
+            amount,
+        });
+
+        Ok(result)
+    }
+
+    /// Set how long future roots stay claimable (admin only, 0 = forever)
+    pub fn set_root_claim_window(&mut self, window: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.root_claim_window.set(window);
+
+        evm::log(RootClaimWindowUpdated { window });
+
+        Ok(())
+    }
+
+    /// Get how long new roots stay claimable (0 = forever)
+    pub fn root_claim_window(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.root_claim_window.get())
+    }
+
+    /// Return the unclaimed part of an expired root to the emission budgets
+    /// (callable by anyone)
+    /// @return Amount released
+    pub fn release_expired_root(&mut self, root_id: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        {
+            let root = self.reward_roots.get(root_id);
+            if !root.exists.get() {
+                return Err(RootNotFound {}.encode());
+            }
+            if !Self::_root_expired(root.expires_at.get()) {
+                return Err(RootNotExpired {}.encode());
+            }
+        }
+
+        self._release_root(root_id)
+    }
+
+    /// Check whether a leaf of a root has been claimed
+    pub fn is_claimed(&self, root_id: FixedBytes<32>, index: U256) -> Result<bool, Vec<u8>> {
+        Ok(self._is_claimed(root_id, index))
+    }
+
+    /// Get the transaction recorded for a claimed leaf
+    /// @return (burnAmount, rewardAmount, player, executed, reversed)
+    pub fn get_claim_transaction(
+        &self,
+        root_id: FixedBytes<32>,
+        index: U256,
+    ) -> Result<(U256, U256, Address, bool, bool), Vec<u8>> {
+        let tx_id = self.compute_claim_tx_id(root_id, index);
+        let tx = self.burn_transactions.get(tx_id);
+
+        Ok((
+            tx.burn_amount.get(),
+            tx.reward_amount.get(),
+            tx.player.get(),
+            tx.executed.get(),
+            tx.reversed.get(),
+        ))
+    }
+
+    /// Get a posted reward root
+    /// @return (merkleRoot, totalAmount, claimedAmount, postedAt)
+    pub fn get_reward_root(
+        &self,
+        root_id: FixedBytes<32>,
+    ) -> Result<(FixedBytes<32>, U256, U256, U256), Vec<u8>> {
+        let root = self.reward_roots.get(root_id);
+        if !root.exists.get() {
+            return Err(RootNotFound {}.encode());
+        }
+
+        Ok((
+            root.merkle_root.get(),
+            root.total_amount.get(),
+            root.claimed_amount.get(),
+            root.posted_at.get(),
+        ))
+    }
+
+    // ==================== Clawback ====================
+
+    /// Set the arbiter allowed to reverse rewards (admin only)
+    pub fn set_arbiter(&mut self, arbiter: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.arbiter.set(arbiter);
+
+        evm::log(ArbiterUpdated { arbiter });
+
+        Ok(())
+    }
+
+    /// Reverse a reward of an overturned match (owner or arbiter).
+    /// Revokes the player's vesting grant, if any, so the unvested part
+    /// returns to the pool; liquid payouts that already left cannot be
+    /// recovered on-chain and only the accounting is reversed.
+    /// @param matchId The match identifier of the transaction
+    /// @param player Player's address
+    /// @return Tokens recovered into the pool
+    pub fn reverse_burn(
+        &mut self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<U256, Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.owner.get() && caller != self.arbiter.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+        if !oracle.is_match_overturned(Call::new(), match_id)? {
+            return Err(MatchNotOverturned {}.encode());
+        }
+
+        let tx_id = self.compute_tx_id(match_id, player);
+        self._reverse_transaction(tx_id, match_id, player)
+    }
+
+    /// Reverse a single Merkle claim (owner or arbiter).
+    /// A match root requires the oracle to report the match as overturned.
+    /// Epoch roots aggregate many matches the contract cannot see, so the
+    /// arbiter decides; use this when a match behind a leaf was overturned.
+    /// Revokes the vesting grant like `reverse_burn`.
+    /// @param rootId Root the claim was made against
+    /// @param index Leaf index of the claim
+    /// @return Tokens recovered into the pool
+    pub fn reverse_claim(&mut self, root_id: FixedBytes<32>, index: U256) -> Result<U256, Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.owner.get() && caller != self.arbiter.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let is_match_root = {
+            let root = self.reward_roots.get(root_id);
+            if !root.exists.get() {
+                return Err(RootNotFound {}.encode());
+            }
+            root.is_match_root.get()
+        };
+
+        if is_match_root {
+            let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+            if !oracle.is_match_overturned(Call::new(), root_id)? {
+                return Err(MatchNotOverturned {}.encode());
+            }
+        }
+
+        let tx_id = self.compute_claim_tx_id(root_id, index);
+        let player = self.burn_transactions.get(tx_id).player.get();
+        self._reverse_transaction(tx_id, root_id, player)
+    }
+
+    /// Stop all further claims on a reward root (owner or arbiter).
+    /// Match roots require the oracle to report the match as overturned.
+    /// The unclaimed remainder never left the pool and stays there; its
+    /// budget reservation is released.
+    /// @param rootId Root identifier
+    /// @return Unclaimed amount kept in the pool
+    pub fn reverse_reward_root(&mut self, root_id: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.owner.get() && caller != self.arbiter.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let (is_match_root, unclaimed) = {
+            let root = self.reward_roots.get(root_id);
+            if !root.exists.get() {
+                return Err(RootNotFound {}.encode());
+            }
+            if root.reversed.get() {
+                return Err(AlreadyReversed {}.encode());
+            }
+            (
+                root.is_match_root.get(),
+                checked_sub(root.total_amount.get(), root.claimed_amount.get())?,
+            )
+        };
+
+        if is_match_root {
+            let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+            if !oracle.is_match_overturned(Call::new(), root_id)? {
+                return Err(MatchNotOverturned {}.encode());
+            }
+        }
+
+        self.reward_roots.setter(root_id).reversed.set(true);
+        if !self.reward_roots.get(root_id).released.get() {
+            self._release_root(root_id)?;
+        }
+
+        evm::log(RewardRootReversed {
+            rootId: root_id,
+            unclaimedAmount: unclaimed,
+        });
+
+        Ok(unclaimed)
+    }
+
+    /// Get clawback status of a burn transaction
+    /// @return (reversed, recoveredAmount)
+    pub fn get_burn_transaction_reversal(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<(bool, U256), Vec<u8>> {
+        let tx_id = self.compute_tx_id(match_id, player);
+        let tx = self.burn_transactions.get(tx_id);
+
+        Ok((tx.reversed.get(), tx.recovered_amount.get()))
+    }
+
+    /// Get total rewards reversed by clawbacks
+    pub fn total_rewards_reversed(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_rewards_reversed.get())
+    }
+
+    /// Get reward tier multiplier (as configured in RewardTiers)
+    /// @param tier The tier (0-7)
+    /// @return (multiplier multiplied by 10, base reward)
+    pub fn get_reward_tier(&self, tier: u8) -> Result<(U256, U256), Vec<u8>> {
+        self._active_tier_config(tier)
+    }
+
+    /// Get total tokens burned
+    pub fn total_burned(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_burned.get())
+    }
+
+    /// Get total rewards distributed
+    pub fn total_rewards(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_rewards_distributed.get())
+    }
+
+    /// Get player's total rewards earned
+    pub fn get_player_rewards(&self, player: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.player_total_rewards.get(player))
+    }
+
+    /// Get player's total tokens burned
+    pub fn get_player_burned(&self, player: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.player_total_burned.get(player))
+    }
+
+    /// Set the default share of rewards withheld from players (admin only)
+    /// @param burnBps Withheld share in bps (1000 = 10%)
+    pub fn set_burn_rate(&mut self, burn_bps: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if burn_bps > U256::from(BPS_DENOMINATOR) {
+            return Err(InvalidBps {}.encode());
+        }
+
+        self.default_burn_bps.set(burn_bps);
+
+        evm::log(BurnRateUpdated {
+            tier: 0,
+            isTierOverride: false,
+            burnBps: burn_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Override the withheld share for a single tier (admin only)
+    /// @param tier The tier (0-7)
+    /// @param burnBps Withheld share in bps
+    pub fn set_tier_burn_rate(&mut self, tier: u8, burn_bps: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if burn_bps > U256::from(BPS_DENOMINATOR) {
+            return Err(InvalidBps {}.encode());
+        }
+
+        // Validates the tier against RewardTiers
+        let reward_tiers = IRewardTiers::new(self.reward_tiers_contract.get());
+        reward_tiers.get_tier_config(Call::new(), tier)?;
+
+        self.tier_burn_bps.setter(tier).set(burn_bps);
+        self.tier_burn_bps_set.setter(tier).set(true);
+
+        evm::log(BurnRateUpdated {
+            tier,
+            isTierOverride: true,
+            burnBps: burn_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Remove a tier override so the tier uses the default rate again (admin only)
+    pub fn clear_tier_burn_rate(&mut self, tier: u8) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        // Validates the tier against RewardTiers
+        let reward_tiers = IRewardTiers::new(self.reward_tiers_contract.get());
+        reward_tiers.get_tier_config(Call::new(), tier)?;
+
+        self.tier_burn_bps.setter(tier).set(U256::from(0));
+        self.tier_burn_bps_set.setter(tier).set(false);
+
+        evm::log(BurnRateUpdated {
+            tier,
+            isTierOverride: false,
+            burnBps: self.default_burn_bps.get(),
+        });
+
+        Ok(())
+    }
+
+    /// Get the withheld share that applies to a tier
+    /// @return (burnBps, isTierOverride)
+    pub fn get_burn_rate(&self, tier: u8) -> Result<(U256, bool), Vec<u8>> {
+        Ok((self._burn_bps(tier), self.tier_burn_bps_set.get(tier)))
+    }
+
+    /// Configure how the withheld share is split (admin only)
+    /// Whatever is not sent to the treasury or community pool is burned.
+    /// @param treasury Treasury address (may be zero when its share is 0)
+    /// @param treasuryShareBps Treasury's cut of the withheld share
+    /// @param communityPool Community pool address (may be zero when its share is 0)
+    /// @param communityShareBps Community pool's cut of the withheld share
+    pub fn set_split_policy(
+        &mut self,
+        treasury: Address,
+        treasury_share_bps: U256,
+        community_pool: Address,
+        community_share_bps: U256,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if checked_add(treasury_share_bps, community_share_bps)? > U256::from(BPS_DENOMINATOR) {
+            return Err(InvalidBps {}.encode());
+        }
+
+        if (treasury == Address::ZERO && treasury_share_bps > U256::from(0))
+            || (community_pool == Address::ZERO && community_share_bps > U256::from(0))
+        {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.treasury.set(treasury);
+        self.treasury_share_bps.set(treasury_share_bps);
+        self.community_pool.set(community_pool);
+        self.community_share_bps.set(community_share_bps);
+
+        evm::log(SplitPolicyUpdated {
+            treasury,
+            treasuryShareBps: treasury_share_bps,
+            communityPool: community_pool,
+            communityShareBps: community_share_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Get the split policy for the withheld share
+    /// @return (treasury, treasuryShareBps, communityPool, communityShareBps)
+    pub fn get_split_policy(&self) -> Result<(Address, U256, Address, U256), Vec<u8>> {
+        Ok((
+            self.treasury.get(),
+            self.treasury_share_bps.get(),
+            self.community_pool.get(),
+            self.community_share_bps.get(),
+        ))
+    }
+
+    /// Get totals sent to the treasury and community pool
+    /// @return (totalToTreasury, totalToCommunity)
+    pub fn get_split_totals(&self) -> Result<(U256, U256), Vec<u8>> {
+        Ok((self.total_to_treasury.get(), self.total_to_community.get()))
+    }
+
+    /// Choose how rewards are funded (admin only).
+    /// When minting, this contract must hold SPPToken's MINTER role; each
+    /// payout then counts against the supply cap and the minter's allowance
+    /// and rate limit, and reverts once they are exhausted.
+    /// @param mintRewards Mint rewards (true) or pay from the pool balance (false)
+    pub fn set_reward_funding(&mut self, mint_rewards: bool) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.mint_rewards.set(mint_rewards);
+
+        evm::log(RewardFundingUpdated {
+            mintRewards: mint_rewards,
+        });
+
+        Ok(())
+    }
+
+    /// Check whether rewards are minted rather than paid from the pool
+    pub fn mints_rewards(&self) -> Result<bool, Vec<u8>> {
+        Ok(self.mint_rewards.get())
+    }
+
+    /// Set the RewardVesting contract (admin only); zero disables vesting
+    pub fn set_vesting_contract(&mut self, vesting_contract: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.vesting_contract.set(vesting_contract);
+
+        evm::log(VestingContractUpdated {
+            vestingContract: vesting_contract,
+        });
+
+        Ok(())
+    }
+
+    /// Configure the vesting schedule for a tier's rewards (admin only)
+    /// @param tier The tier (0-7)
+    /// @param enabled Whether rewards of this tier vest
+    /// @param cliff Seconds before anything vests
+    /// @param duration Seconds until fully vested (linear schedules: non-zero, at least the cliff)
+    /// @param linear Linear after the cliff, or everything at the cliff
+    pub fn set_tier_vesting(
+        &mut self,
+        tier: u8,
+        enabled: bool,
+        cliff: U256,
+        duration: U256,
+        linear: bool,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        // Same rule RewardVesting applies when the grant is created
+        if enabled && linear && (duration == U256::from(0) || cliff > duration) {
+            return Err(InvalidSchedule {}.encode());
+        }
+
+        // Validates the tier against RewardTiers
+        let reward_tiers = IRewardTiers::new(self.reward_tiers_contract.get());
+        reward_tiers.get_tier_config(Call::new(), tier)?;
+
+        let mut schedule = self.tier_vesting.setter(tier);
+        schedule.enabled.set(enabled);
+        schedule.cliff.set(cliff);
+        schedule.duration.set(duration);
+        schedule.linear.set(linear);
+
+        evm::log(TierVestingUpdated {
+            tier,
+            enabled,
+            cliff,
+            duration,
+            linear,
+        });
+
+        Ok(())
+    }
+
+    /// Get the vesting schedule for a tier
+    /// @return (enabled, cliff, duration, linear)
+    pub fn get_tier_vesting(&self, tier: u8) -> Result<(bool, U256, U256, bool), Vec<u8>> {
+        let schedule = self.tier_vesting.get(tier);
+
+        Ok((
+            schedule.enabled.get(),
+            schedule.cliff.get(),
+            schedule.duration.get(),
+            schedule.linear.get(),
+        ))
+    }
+
+    /// Configure emission budgets (admin only). A budget of 0 means unlimited.
+    /// @param epochLength Epoch length in seconds (must be > 0)
+    /// @param epochBudget Max gross rewards per epoch
+    /// @param matchBudget Max gross rewards per match
+    /// @param playerEpochCap Max gross rewards per player per epoch
+    pub fn set_emission_budgets(
+        &mut self,
+        epoch_length: U256,
+        epoch_budget: U256,
+        match_budget: U256,
+        player_epoch_cap: U256,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if epoch_length == U256::from(0) {
+            return Err(InvalidEpochLength {}.encode());
+        }
+
+        self.epoch_length.set(epoch_length);
+        self.epoch_budget.set(epoch_budget);
+        self.match_budget.set(match_budget);
+        self.player_epoch_cap.set(player_epoch_cap);
+
+        evm::log(EmissionBudgetsUpdated {
+            epochLength: epoch_length,
+            epochBudget: epoch_budget,
+            matchBudget: match_budget,
+            playerEpochCap: player_epoch_cap,
+        });
+
+        Ok(())
+    }
+
+    /// Get emission budget configuration
+    /// @return (epochLength, epochBudget, matchBudget, playerEpochCap)
+    pub fn get_emission_budgets(&self) -> Result<(U256, U256, U256, U256), Vec<u8>> {
+        Ok((
+            self.epoch_length.get(),
+            self.epoch_budget.get(),
+            self.match_budget.get(),
+            self.player_epoch_cap.get(),
+        ))
+    }
+
+    /// Get the current emission epoch index
+    pub fn current_epoch(&self) -> Result<U256, Vec<u8>> {
+        Ok(self._current_epoch())
+    }
+
+    /// Remaining gross rewards for the current epoch (max uint256 if unlimited)
+    pub fn remaining_epoch_budget(&self) -> Result<U256, Vec<u8>> {
+        Ok(self._remaining_epoch_budget())
+    }
+
+    /// Remaining gross rewards for a match (max uint256 if unlimited)
+    pub fn remaining_match_budget(&self, match_id: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        Ok(self._remaining_match_budget(match_id))
+    }
+
+    /// Remaining gross rewards a player can receive this epoch (max uint256 if unlimited)
+    pub fn remaining_player_allowance(&self, player: Address) -> Result<U256, Vec<u8>> {
+        Ok(self._remaining_player_allowance(player))
+    }
+
+    /// Get burn transaction details
+    pub fn get_burn_transaction(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
     ) -> Result<(U256, U256, u8, bool), Vec<u8>> {
         let tx_id = self.compute_tx_id(match_id, player);
         let tx = self.burn_transactions.get(tx_id);
 
         Ok((
-            tx.burn_amount.get(),
-            tx.reward_amount.get(),
-            tx.tier.get(),
-            tx.executed.get(),
+            tx.burn_amount.get(),
+            tx.reward_amount.get(),
+            tx.tier.get(),
+            tx.executed.get(),
+        ))
+    }
+
+    /// Get how a burn transaction's withheld share was split
+    /// @return (treasuryAmount, communityAmount)
+    pub fn get_burn_transaction_split(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        let tx_id = self.compute_tx_id(match_id, player);
+        let tx = self.burn_transactions.get(tx_id);
+
+        Ok((tx.treasury_amount.get(), tx.community_amount.get()))
+    }
+
+    /// Get the vesting grant holding a burn transaction's player share (0 = liquid)
+    pub fn get_burn_transaction_grant(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<U256, Vec<u8>> {
+        let tx_id = self.compute_tx_id(match_id, player);
+        Ok(self.burn_transactions.get(tx_id).vesting_grant_id.get())
+    }
+
+    /// Get the RewardTiers config version a burn transaction was computed with
+    /// (query `RewardTiers.getTierAtVersion` to reconstruct its multiplier)
+    pub fn get_burn_transaction_version(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<U256, Vec<u8>> {
+        let tx_id = self.compute_tx_id(match_id, player);
+        Ok(self.burn_transactions.get(tx_id).config_version.get())
+    }
+}
+
+// ==================== Internal Functions ====================
+
+impl DeflatinaryBurn {
+    /// Read the current (multiplier, base reward) for a tier from RewardTiers,
+    /// rejecting tiers that have been deactivated there
+    fn _active_tier_config(&self, tier: u8) -> Result<(U256, U256), Vec<u8>> {
+        let reward_tiers = IRewardTiers::new(self.reward_tiers_contract.get());
+        let (multiplier, base_reward, is_active) =
+            reward_tiers.get_tier_config(Call::new(), tier)?;
+
+        if !is_active {
+            return Err(TierNotActive {}.encode());
+        }
+
+        Ok((multiplier, base_reward))
+    }
+
+    /// Read (multiplier, base reward) for a tier as of a pinned config version
+    fn _active_tier_config_at_version(
+        &self,
+        tier: u8,
+        version: U256,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        let reward_tiers = IRewardTiers::new(self.reward_tiers_contract.get());
+        let (multiplier, base_reward, is_active) =
+            reward_tiers.get_tier_at_version(Call::new(), tier, version)?;
+
+        if !is_active {
+            return Err(TierNotActive {}.encode());
+        }
+
+        Ok((multiplier, base_reward))
+    }
+
+    /// Reward for a tier's base reward and multiplier at an effort score
+    fn _apply_reward_formula(
+        &self,
+        base_reward: U256,
+        multiplier: U256,
+        effort_score: U256,
+    ) -> Result<U256, Vec<u8>> {
+        performance_reward(base_reward, multiplier, self._effort_factor(effort_score))
+    }
+
+    /// Map an effort score (0-100) through the effort curve to a factor in bps
+    fn _effort_factor(&self, effort_score: U256) -> U256 {
+        let knees: Vec<(U256, U256)> = (0..self.effort_knee_x.len())
+            .map(|i| {
+                (
+                    self.effort_knee_x.get(i).unwrap_or_default(),
+                    self.effort_knee_y.get(i).unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        effort_curve_factor(
+            effort_score,
+            self.effort_min_threshold.get(),
+            self.effort_floor_bps.get(),
+            self.effort_cap.get(),
+            &knees,
+        )
+    }
+
+    /// Config version the oracle pinned for a match
+    /// (fails for unknown and overturned matches)
+    fn _match_config_version(&self, match_id: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+
+        if oracle.is_match_overturned(Call::new(), match_id)? {
+            return Err(MatchOverturned {}.encode());
+        }
+
+        Ok(oracle.get_match_config_version(Call::new(), match_id)?)
+    }
+
+    /// Gross reward for a tier/effort at a pinned config version
+    /// @return (reward, baseReward)
+    fn _compute_reward(
+        &self,
+        tier: u8,
+        effort_score: U256,
+        config_version: U256,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        // Validate effort score (must be 0-100)
+        if effort_score > U256::from(100) {
+            return Err(InvalidEffortScore {}.encode());
+        }
+
+        let (burn_multiplier, base_reward) =
+            self._active_tier_config_at_version(tier, config_version)?;
+
+        Ok((
+            self._apply_reward_formula(base_reward, burn_multiplier, effort_score)?,
+            base_reward,
         ))
     }
 
+    /// Record, account and pay out one player's reward.
+    /// `granted` has already been capped by match/epoch budgets; the
+    /// per-player epoch cap is applied here. Reverts instead of recording
+    /// an executed zero reward when the budgets are exhausted.
+    #[allow(clippy::too_many_arguments)]
+    fn _settle(
+        &mut self,
+        match_id: FixedBytes<32>,
+        player: Address,
+        tier: u8,
+        effort_score: U256,
+        config_version: U256,
+        base_reward: U256,
+        requested: U256,
+        granted: U256,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        let reward_amount = granted.min(self._remaining_player_allowance(player));
+        if reward_amount == U256::from(0) && requested > U256::from(0) {
+            return Err(BudgetExceeded {}.encode());
+        }
+
+        if reward_amount < requested {
+            evm::log(RewardCapped {
+                matchId: match_id,
+                player,
+                requestedAmount: requested,
+                grantedAmount: reward_amount,
+            });
+        }
+
+        // Consume emission budgets
+        self._record_emission(match_id, player, reward_amount)?;
+
+        self._record_reward(
+            match_id,
+            player,
+            tier,
+            effort_score,
+            config_version,
+            base_reward,
+            reward_amount,
+            None,
+        )
+    }
+
+    /// Record a reward transaction, update totals and pay it out of the pool
+    /// `claim_index` is the leaf index for Merkle claims (`match_id` is then
+    /// the root ID); each leaf is its own transaction.
+    #[allow(clippy::too_many_arguments)]
+    fn _record_reward(
+        &mut self,
+        match_id: FixedBytes<32>,
+        player: Address,
+        tier: u8,
+        effort_score: U256,
+        config_version: U256,
+        base_reward: U256,
+        reward_amount: U256,
+        claim_index: Option<U256>,
+    ) -> Result<(U256, U256), Vec<u8>> {
+        // Create transaction ID
+        let merkle_claim = claim_index.is_some();
+        let tx_id = match claim_index {
+            Some(index) => self.compute_claim_tx_id(match_id, index),
+            None => self.compute_tx_id(match_id, player),
+        };
+
+        // Check if already executed
+        let existing_tx = self.burn_transactions.get(tx_id);
+        if existing_tx.executed.get() {
+            return Err(BurnAlreadyExecuted {}.encode());
+        }
+
+        // Split off the withheld share (burn + treasury + community)
+        let (burn_amount, treasury_amount, community_amount) =
+            self._split_reward(tier, reward_amount)?;
+
+        // Record transaction
+        let mut tx = self.burn_transactions.setter(tx_id);
+        tx.match_id.set(match_id);
+        tx.player.set(player);
+        tx.burn_amount.set(burn_amount);
+        tx.treasury_amount.set(treasury_amount);
+        tx.community_amount.set(community_amount);
+        tx.reward_amount.set(reward_amount);
+        tx.tier.set(tier);
+        tx.effort_score.set(effort_score);
+        tx.timestamp.set(U256::from(block::timestamp()));
+        tx.config_version.set(config_version);
+        tx.merkle_claim.set(merkle_claim);
+        tx.executed.set(true);
+
+        // Update totals
+        let current_burned = self.total_burned.get();
+        self.total_burned.set(checked_add(current_burned, burn_amount)?);
+
+        let current_rewards = self.total_rewards_distributed.get();
+        self.total_rewards_distributed.set(checked_add(current_rewards, reward_amount)?);
+
+        // Update player totals
+        let player_rewards = self.player_total_rewards.get(player);
+        self.player_total_rewards.setter(player).set(checked_add(player_rewards, reward_amount)?);
+
+        let player_burned = self.player_total_burned.get(player);
+        self.player_total_burned.setter(player).set(checked_add(player_burned, burn_amount)?);
+
+        // Move tokens out of the reward pool
+        let grant_id = self._pay_out(
+            player,
+            tier,
+            reward_amount,
+            burn_amount,
+            treasury_amount,
+            community_amount,
+        )?;
+        self.burn_transactions.setter(tx_id).vesting_grant_id.set(grant_id);
+
+        // Emit events
+        evm::log(RewardCalculated {
+            player,
+            tier,
+            baseReward: base_reward,
+            effortMultiplier: effort_score,
+            finalReward: reward_amount,
+        });
+
+        evm::log(TokensBurned {
+            matchId: match_id,
+            player,
+            burnAmount: burn_amount,
+            treasuryAmount: treasury_amount,
+            communityAmount: community_amount,
+            rewardAmount: reward_amount,
+            tier,
+        });
+
+        Ok((burn_amount, reward_amount))
+    }
+
+    /// Current emission epoch index
+    fn _current_epoch(&self) -> U256 {
+        U256::from(block::timestamp()) / self.epoch_length.get()
+    }
+
+    /// Remaining allowance under a budget (0 budget = unlimited)
+    fn _remaining(budget: U256, used: U256) -> U256 {
+        if budget == U256::from(0) {
+            U256::MAX
+        } else {
+            budget.saturating_sub(used)
+        }
+    }
+
+    fn _remaining_epoch_budget(&self) -> U256 {
+        Self::_remaining(self.epoch_budget.get(), self.epoch_emitted.get(self._current_epoch()))
+    }
+
+    fn _remaining_match_budget(&self, match_id: FixedBytes<32>) -> U256 {
+        Self::_remaining(self.match_budget.get(), self.match_emitted.get(match_id))
+    }
+
+    fn _remaining_player_allowance(&self, player: Address) -> U256 {
+        let used = self.player_epoch_emitted.get(self._current_epoch()).get(player);
+        Self::_remaining(self.player_epoch_cap.get(), used)
+    }
+
+    /// Count a gross reward against the epoch, match and player budgets
+    fn _record_emission(
+        &mut self,
+        match_id: FixedBytes<32>,
+        player: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        let epoch = self._current_epoch();
+
+        let epoch_total = self.epoch_emitted.get(epoch);
+        self.epoch_emitted.setter(epoch).set(checked_add(epoch_total, amount)?);
+
+        let match_total = self.match_emitted.get(match_id);
+        self.match_emitted.setter(match_id).set(checked_add(match_total, amount)?);
+
+        let player_total = self.player_epoch_emitted.get(epoch).get(player);
+        self.player_epoch_emitted
+            .setter(epoch)
+            .setter(player)
+            .set(checked_add(player_total, amount)?);
+
+        Ok(())
+    }
+
+    /// Withheld share in bps for a tier (override or default)
+    fn _burn_bps(&self, tier: u8) -> U256 {
+        if self.tier_burn_bps_set.get(tier) {
+            self.tier_burn_bps.get(tier)
+        } else {
+            self.default_burn_bps.get()
+        }
+    }
+
+    /// Split a reward's withheld share into (burn, treasury, community)
+    /// Rounding favours the protocol: the withheld share rounds up, the
+    /// treasury/community cuts round down and the remainder is burned
+    fn _split_reward(&self, tier: u8, reward_amount: U256) -> Result<(U256, U256, U256), Vec<u8>> {
+        split_withheld(
+            reward_amount,
+            self._burn_bps(tier),
+            self.treasury_share_bps.get(),
+            self.community_share_bps.get(),
+        )
+    }
+
+    /// Pay a reward out of the pool: the player gets the reward minus the
+    /// withheld share, which is routed to treasury/community or burned.
+    /// If the tier vests, the player's share goes into a vesting grant.
+    /// @return Vesting grant ID (0 if paid liquid)
+    fn _pay_out(
+        &mut self,
+        player: Address,
+        tier: u8,
+        reward_amount: U256,
+        burn_amount: U256,
+        treasury_amount: U256,
+        community_amount: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let token = ISPPToken::new(self.token_contract.get());
+        let treasury = self.treasury.get();
+        let community_pool = self.community_pool.get();
+        let player_amount = checked_sub(
+            reward_amount,
+            checked_add(checked_add(burn_amount, treasury_amount)?, community_amount)?,
+        )?;
+
+        // Mint the gross reward into the pool; the withheld share is then
+        // burned exactly as in pool-funded mode
+        if self.mint_rewards.get() && reward_amount > U256::from(0) {
+            token.mint(Call::new_in(self), contract::address(), reward_amount)?;
+        }
+
+        let vesting_contract = self.vesting_contract.get();
+        let (vests, cliff, duration, linear) = {
+            let schedule = self.tier_vesting.get(tier);
+            (
+                schedule.enabled.get() && vesting_contract != Address::ZERO,
+                schedule.cliff.get(),
+                schedule.duration.get(),
+                schedule.linear.get(),
+            )
+        };
+
+        let mut grant_id = U256::from(0);
+        if player_amount > U256::from(0) {
+            if vests {
+                if !token.transfer(Call::new_in(self), vesting_contract, player_amount)? {
+                    return Err(TokenTransferFailed {}.encode());
+                }
+                let vesting = IRewardVesting::new(vesting_contract);
+                grant_id = vesting.create_grant(
+                    Call::new_in(self),
+                    player,
+                    player_amount,
+                    cliff,
+                    duration,
+                    linear,
+                )?;
+            } else if !token.transfer(Call::new_in(self), player, player_amount)? {
+                return Err(TokenTransferFailed {}.encode());
+            }
+        }
+
+        if treasury_amount > U256::from(0) {
+            if !token.transfer(Call::new_in(self), treasury, treasury_amount)? {
+                return Err(TokenTransferFailed {}.encode());
+            }
+            let total = self.total_to_treasury.get();
+            self.total_to_treasury.set(checked_add(total, treasury_amount)?);
+        }
+
+        if community_amount > U256::from(0) {
+            if !token.transfer(Call::new_in(self), community_pool, community_amount)? {
+                return Err(TokenTransferFailed {}.encode());
+            }
+            let total = self.total_to_community.get();
+            self.total_to_community.set(checked_add(total, community_amount)?);
+        }
+
+        if burn_amount > U256::from(0) {
+            token.burn(Call::new_in(self), burn_amount)?;
+        }
+
+        Ok(grant_id)
+    }
+
+    /// Revoke a settled transaction's vesting grant and reverse its accounting
+    fn _reverse_transaction(
+        &mut self,
+        tx_id: FixedBytes<32>,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<U256, Vec<u8>> {
+        let (reward_amount, grant_id) = {
+            let tx = self.burn_transactions.get(tx_id);
+            if !tx.executed.get() {
+                return Err(BurnNotExecuted {}.encode());
+            }
+            if tx.reversed.get() {
+                return Err(AlreadyReversed {}.encode());
+            }
+            (tx.reward_amount.get(), tx.vesting_grant_id.get())
+        };
+
+        let mut recovered = U256::from(0);
+        if grant_id > U256::from(0) {
+            let vesting = IRewardVesting::new(self.vesting_contract.get());
+            recovered = vesting.revoke(Call::new_in(self), grant_id, contract::address())?;
+        }
+
+        let mut tx = self.burn_transactions.setter(tx_id);
+        tx.reversed.set(true);
+        tx.recovered_amount.set(recovered);
+
+        // Reverse the reward accounting
+        let player_rewards = self.player_total_rewards.get(player);
+        self.player_total_rewards.setter(player).set(checked_sub(player_rewards, reward_amount)?);
+
+        let current_rewards = self.total_rewards_distributed.get();
+        self.total_rewards_distributed.set(checked_sub(current_rewards, reward_amount)?);
+
+        let reversed = self.total_rewards_reversed.get();
+        self.total_rewards_reversed.set(checked_add(reversed, reward_amount)?);
+
+        evm::log(RewardReversed {
+            matchId: match_id,
+            player,
+            rewardAmount: reward_amount,
+            recoveredAmount: recovered,
+        });
+
+        Ok(recovered)
+    }
+
+    /// Whether a root's claim window has closed (0 = never expires)
+    fn _root_expired(expires_at: U256) -> bool {
+        expires_at > U256::from(0) && U256::from(block::timestamp()) >= expires_at
+    }
+
+    /// Release a root's unclaimed reservation from the epoch and match budgets
+    fn _release_root(&mut self, root_id: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        let (unclaimed, epoch, is_match_root) = {
+            let root = self.reward_roots.get(root_id);
+            if root.released.get() {
+                return Err(RootAlreadyReleased {}.encode());
+            }
+            (
+                checked_sub(root.total_amount.get(), root.claimed_amount.get())?,
+                root.epoch.get(),
+                root.is_match_root.get(),
+            )
+        };
+
+        self.reward_roots.setter(root_id).released.set(true);
+
+        let epoch_total = self.epoch_emitted.get(epoch);
+        self.epoch_emitted.setter(epoch).set(epoch_total.saturating_sub(unclaimed));
+
+        if is_match_root {
+            let match_total = self.match_emitted.get(root_id);
+            self.match_emitted.setter(root_id).set(match_total.saturating_sub(unclaimed));
+        }
+
+        evm::log(RewardRootReleased {
+            rootId: root_id,
+            releasedAmount: unclaimed,
+        });
+
+        Ok(unclaimed)
+    }
+
+    /// Merkle leaf: keccak256(index, player, tier, amount), tightly packed
+    fn _reward_leaf(index: U256, player: Address, tier: u8, amount: U256) -> FixedBytes<32> {
+        let mut data = [0u8; 85];
+        data[0..32].copy_from_slice(&index.to_be_bytes::<32>());
+        data[32..52].copy_from_slice(&player.0 .0);
+        data[52] = tier;
+        data[53..85].copy_from_slice(&amount.to_be_bytes::<32>());
+        FixedBytes::<32>::from_slice(&stylus_sdk::crypto::keccak(&data))
+    }
+
+    /// Verify a Merkle proof using sorted-pair hashing
+    fn _verify_proof(
+        proof: &[FixedBytes<32>],
+        root: FixedBytes<32>,
+        leaf: FixedBytes<32>,
+    ) -> bool {
+        let mut computed = leaf;
+        for sibling in proof {
+            let (first, second) = if computed <= *sibling {
+                (computed, *sibling)
+            } else {
+                (*sibling, computed)
+            };
+
+            let mut data = [0u8; 64];
+            data[0..32].copy_from_slice(&first.0);
+            data[32..64].copy_from_slice(&second.0);
+            computed = FixedBytes::<32>::from_slice(&stylus_sdk::crypto::keccak(&data));
+        }
+
+        computed == root
+    }
+
+    fn _is_claimed(&self, root_id: FixedBytes<32>, index: U256) -> bool {
+        let (word_index, bit) = claimed_bit(index);
+        let word = self.claimed_bitmaps.get(root_id).get(word_index);
+        word & bit != U256::from(0)
+    }
+
+    fn _set_claimed(&mut self, root_id: FixedBytes<32>, index: U256) {
+        let (word_index, bit) = claimed_bit(index);
+        let word = self.claimed_bitmaps.get(root_id).get(word_index);
+        self.claimed_bitmaps
+            .setter(root_id)
+            .setter(word_index)
+            .set(word | bit);
+    }
+
+    /// Compute the transaction ID of a Merkle claim from root and leaf index
+    fn compute_claim_tx_id(&self, root_id: FixedBytes<32>, index: U256) -> FixedBytes<32> {
+        let mut data = [0u8; 64];
+        data[0..32].copy_from_slice(&root_id.0);
+        data[32..64].copy_from_slice(&index.to_be_bytes::<32>());
+        FixedBytes::<32>::from_slice(&stylus_sdk::crypto::keccak(&data))
+    }
+
     /// Compute transaction ID from match and player
     fn compute_tx_id(&self, match_id: FixedBytes<32>, player: Address) -> FixedBytes<32> {
         // Simple hash: keccak256(matchId, player)
@@ -344,3 +2036,101 @@ impl DeflatinaryBurn {
         FixedBytes::<32>::from_slice(&stylus_sdk::crypto::keccak(&data))
     }
 }
+
+// ==================== Reward Math Helpers ====================
+
+/// Reward = base * (effortFactor / 10000) * (multiplier / 10), in base units.
+/// Both ratios are applied in a single mul-div so there is one truncation.
+/// @param multiplier Burn multiplier, stored multiplied by 10
+pub fn performance_reward(
+    base_reward: U256,
+    multiplier: U256,
+    effort_factor_bps: U256,
+) -> Result<U256, Vec<u8>> {
+    mul_div(
+        base_reward,
+        checked_mul(effort_factor_bps, multiplier)?,
+        U256::from(BPS_DENOMINATOR * 10),
+        Rounding::Down,
+    )
+}
+
+/// Split the withheld share of a reward into (burn, treasury, community)
+/// Rounding favours the protocol: the withheld share rounds up, the
+/// treasury/community cuts round down and the remainder is burned
+pub fn split_withheld(
+    reward_amount: U256,
+    burn_bps: U256,
+    treasury_share_bps: U256,
+    community_share_bps: U256,
+) -> Result<(U256, U256, U256), Vec<u8>> {
+    let bps = U256::from(BPS_DENOMINATOR);
+    let withheld = mul_div(reward_amount, burn_bps, bps, Rounding::Up)?;
+
+    let treasury_amount = mul_div(withheld, treasury_share_bps, bps, Rounding::Down)?;
+    let community_amount = mul_div(withheld, community_share_bps, bps, Rounding::Down)?;
+    let burn_amount = checked_sub(withheld, checked_add(treasury_amount, community_amount)?)?;
+
+    Ok((burn_amount, treasury_amount, community_amount))
+}
+
+/// Scale one reward down pro-rata when the combined request exceeds the
+/// available budget (rounding down, so the total never exceeds it)
+pub fn scale_pro_rata(
+    requested: U256,
+    total_requested: U256,
+    available: U256,
+) -> Result<U256, Vec<u8>> {
+    if total_requested <= available {
+        return Ok(requested);
+    }
+    mul_div(requested, available, total_requested, Rounding::Down)
+}
+
+/// Piecewise effort curve: 0 below the threshold, linear between knees
+/// (x = effort, y = factor in bps), flat outside them, clamped at the cap
+/// and never below the floor
+pub fn effort_curve_factor(
+    effort_score: U256,
+    min_threshold: U256,
+    floor_bps: U256,
+    cap: U256,
+    knees: &[(U256, U256)],
+) -> U256 {
+    if effort_score < min_threshold {
+        return U256::from(0);
+    }
+
+    let effort = effort_score.min(cap);
+
+    // Flat before the first knee
+    let mut factor = knees.first().map(|&(_, y)| y).unwrap_or_default();
+
+    for (i, &(x, y)) in knees.iter().enumerate() {
+        if effort < x {
+            if i > 0 {
+                // Linear interpolation between knee i-1 and knee i
+                let (x0, y0) = knees[i - 1];
+                factor = if y >= y0 {
+                    y0 + ((y - y0) * (effort - x0)) / (x - x0)
+                } else {
+                    y0 - ((y0 - y) * (effort - x0)) / (x - x0)
+                };
+            }
+            break;
+        }
+
+        // At or past this knee (flat after the last one)
+        factor = y;
+    }
+
+    factor.max(floor_bps)
+}
+
+/// Position of a leaf in a root's claimed bitmap
+/// @return (word index, bit mask within the word)
+pub fn claimed_bit(index: U256) -> (U256, U256) {
+    let word_index = index / U256::from(256);
+    let bit = U256::from(1) << (index % U256::from(256)).to::<usize>();
+    (word_index, bit)
+}
@@ -0,0 +1,118 @@
+//! # EIP-712 Typed Data Helpers
+//!
+//! Shared signature plumbing for gasless, relayed actions (EIP-2612 permits,
+//! vote delegation by signature, verifier attestations).
+//!
+//! ## Notes:
+//! - Domain: `EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)`
+//! - Signatures are recovered with the `ecrecover` precompile (0x01)
+//! - High-`s` signatures are rejected (EIP-2) to prevent malleability
+
+use stylus_sdk::{
+    alloy_primitives::{address, Address, FixedBytes, U256},
+    block,
+    call::{static_call, Call},
+    contract,
+    crypto::keccak,
+    prelude::*,
+};
+
+/// `ecrecover` precompile
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+
+/// secp256k1n / 2, the largest accepted `s` value
+const MAX_S: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
+sol! {
+    error InvalidSignature();
+}
+
+/// keccak256 of a type string or other raw bytes
+pub fn hash_bytes(data: &[u8]) -> FixedBytes<32> {
+    keccak(data)
+}
+
+/// Left-pad an address into a 32-byte ABI word
+pub fn address_word(value: Address) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..32].copy_from_slice(value.as_slice());
+    word
+}
+
+/// Encode a uint256 as a 32-byte ABI word
+pub fn uint_word(value: U256) -> [u8; 32] {
+    value.to_be_bytes::<32>()
+}
+
+/// keccak256 of concatenated 32-byte words (`abi.encode` of static types)
+pub fn hash_words(words: &[[u8; 32]]) -> FixedBytes<32> {
+    let mut data = Vec::with_capacity(words.len() * 32);
+    for word in words {
+        data.extend_from_slice(word);
+    }
+    keccak(&data)
+}
+
+/// EIP-712 domain separator for the calling contract
+pub fn domain_separator(name: &str, version: &str) -> FixedBytes<32> {
+    let type_hash = hash_bytes(
+        b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+    );
+
+    hash_words(&[
+        type_hash.0,
+        hash_bytes(name.as_bytes()).0,
+        hash_bytes(version.as_bytes()).0,
+        uint_word(U256::from(block::chainid())),
+        address_word(contract::address()),
+    ])
+}
+
+/// Final EIP-712 digest: keccak256("\x19\x01" ‖ domainSeparator ‖ structHash)
+pub fn typed_data_hash(
+    domain_separator: FixedBytes<32>,
+    struct_hash: FixedBytes<32>,
+) -> FixedBytes<32> {
+    let mut data = [0u8; 66];
+    data[0] = 0x19;
+    data[1] = 0x01;
+    data[2..34].copy_from_slice(&domain_separator.0);
+    data[34..66].copy_from_slice(&struct_hash.0);
+    keccak(data)
+}
+
+/// Recover the signer of a digest, reverting with `InvalidSignature` for
+/// malformed, malleable or unrecoverable signatures
+pub fn recover_signer(
+    digest: FixedBytes<32>,
+    v: u8,
+    r: FixedBytes<32>,
+    s: FixedBytes<32>,
+) -> Result<Address, Vec<u8>> {
+    if (v != 27 && v != 28) || U256::from_be_bytes(s.0) > MAX_S {
+        return Err(InvalidSignature {}.encode());
+    }
+
+    let mut input = [0u8; 128];
+    input[0..32].copy_from_slice(&digest.0);
+    input[63] = v;
+    input[64..96].copy_from_slice(&r.0);
+    input[96..128].copy_from_slice(&s.0);
+
+    let output = static_call(Call::new(), ECRECOVER, &input)?;
+    if output.len() != 32 {
+        return Err(InvalidSignature {}.encode());
+    }
+
+    let signer = Address::from_slice(&output[12..32]);
+    if signer == Address::ZERO {
+        return Err(InvalidSignature {}.encode());
+    }
+
+    Ok(signer)
+}
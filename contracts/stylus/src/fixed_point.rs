@@ -0,0 +1,103 @@
+//! # Fixed-Point & Checked Math
+//!
+//! Integer helpers shared by all contracts.
+//! Token amounts are handled in SPPToken base units (18 decimals), and
+//! ratios are applied with a full-precision `mul_div` so that
+//! `a * b / denominator` neither overflows in the intermediate product nor
+//! truncates more than once.
+//!
+//! ## Conventions:
+//! - `TOKEN_UNIT` is one whole SPP token in base units (10^18)
+//! - Rounding is explicit at every call site via `Rounding`
+//! - Storage arithmetic goes through `checked_add` / `checked_sub` /
+//!   `checked_mul`, which revert with `Overflow` / `Underflow` instead of
+//!   wrapping or panicking
+//! - Errors are ABI-encoded like every other contract error
+
+use stylus_sdk::{
+    alloy_primitives::{U256, U512},
+    prelude::*,
+};
+
+/// SPPToken decimals
+pub const TOKEN_DECIMALS: u8 = 18;
+
+/// One whole token in base units (10^18)
+pub const TOKEN_UNIT: U256 = U256::from_limbs([1_000_000_000_000_000_000, 0, 0, 0]);
+
+sol! {
+    error Overflow();
+    error Underflow();
+    error DivisionByZero();
+}
+
+/// Rounding mode for `mul_div`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards zero (truncate)
+    Down,
+    /// Away from zero when there is a remainder
+    Up,
+    /// To the nearest integer, halves rounded up
+    Nearest,
+}
+
+/// Compute `a * b / denominator` with a 512-bit intermediate product
+pub fn mul_div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Result<U256, Vec<u8>> {
+    if denominator == U256::ZERO {
+        return Err(DivisionByZero {}.encode());
+    }
+
+    let product = widen(a) * widen(b);
+    let wide_denominator = widen(denominator);
+    let mut quotient = product / wide_denominator;
+    let remainder = product % wide_denominator;
+
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::Up => remainder != U512::ZERO,
+        // remainder * 2 cannot overflow: remainder < denominator < 2^256
+        Rounding::Nearest => remainder * U512::from(2) >= wide_denominator,
+    };
+    if round_up {
+        quotient += U512::from(1);
+    }
+
+    narrow(quotient)
+}
+
+/// `a + b`, reverting with `Overflow`
+pub fn checked_add(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+    a.checked_add(b).ok_or_else(|| Overflow {}.encode())
+}
+
+/// `a - b`, reverting with `Underflow`
+pub fn checked_sub(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+    a.checked_sub(b).ok_or_else(|| Underflow {}.encode())
+}
+
+/// `a * b`, reverting with `Overflow`
+pub fn checked_mul(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+    a.checked_mul(b).ok_or_else(|| Overflow {}.encode())
+}
+
+/// Convert a whole-token amount into base units
+pub fn to_base_units(whole_tokens: U256) -> Result<U256, Vec<u8>> {
+    checked_mul(whole_tokens, TOKEN_UNIT)
+}
+
+/// Zero-extend a 256-bit value to 512 bits
+fn widen(value: U256) -> U512 {
+    let limbs = value.as_limbs();
+    U512::from_limbs([limbs[0], limbs[1], limbs[2], limbs[3], 0, 0, 0, 0])
+}
+
+/// Truncate a 512-bit value back to 256 bits, failing if it does not fit
+fn narrow(value: U512) -> Result<U256, Vec<u8>> {
+    let limbs = value.as_limbs();
+    if limbs[4..].iter().any(|&limb| limb != 0) {
+        return Err(Overflow {}.encode());
+    }
+
+    Ok(U256::from_limbs([limbs[0], limbs[1], limbs[2], limbs[3]]))
+}
@@ -9,6 +9,9 @@
 //! - **SPPToken**: ERC-20 token with burn capabilities
 //! - **RewardTiers**: On-chain reward tier configuration
 //! - **AthleteNFT**: Computational NFT for athlete profiles (Living Resume)
+//! - **RewardVesting**: Optional vesting grants for athlete reward payouts
+//! - **SPPStaking**: SPP staking with a streamed reward share and unbonding
+//! - **PredictionPool**: Fan prediction markets settled by oracle outcomes
 
 #![cfg_attr(not(feature = "export-abi"), no_main)]
 extern crate alloc;
@@ -28,13 +31,34 @@ mod deflatinary_burn;
 mod spp_token;
 mod reward_tiers;
 mod athlete_nft;
+mod reward_vesting;
+mod spp_staking;
+mod prediction_pool;
+mod fixed_point;
+mod eip712;
+mod nft_metadata;
 
 // Re-export main contracts
 pub use performance_oracle::PerformanceOracle;
-pub use deflatinary_burn::DeflatinaryBurn;
-pub use spp_token::SPPToken;
+pub use deflatinary_burn::{
+    claimed_bit, effort_curve_factor, performance_reward, scale_pro_rata, split_withheld,
+    DeflatinaryBurn,
+};
+pub use spp_token::{apply_mint_limits, checkpoints_at_or_before, circulating_amount, SPPToken};
 pub use reward_tiers::RewardTiers;
-pub use athlete_nft::AthleteNFT;
+pub use athlete_nft::{
+    blend, decay_fold, form_ratio, history_page, is_supported_interface, range_score,
+    recent_history, AthleteNFT, DEFAULT_POWER_STRIKE_RATE_BPS, ERC721_RECEIVED, INTERFACE_ERC165,
+    INTERFACE_ERC721, INTERFACE_ERC721_METADATA, RUNS_PER_MATCH_RANGE, STRIKE_RATE_RANGE,
+};
+pub use reward_vesting::RewardVesting;
+pub use spp_staking::{accrue_reward_per_token, earned_rewards, SPPStaking};
+pub use prediction_pool::{settlement_fee, winning_payout, PredictionPool};
+pub use nft_metadata::{base64_encode, escape_json, escape_xml, AthleteCard};
+pub use fixed_point::{
+    checked_add, checked_mul, checked_sub, mul_div, to_base_units, Rounding, TOKEN_DECIMALS,
+    TOKEN_UNIT,
+};
 
 // Common types and errors used across contracts
 sol! {
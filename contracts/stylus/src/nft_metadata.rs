@@ -0,0 +1,220 @@
+//! # NFT Metadata Rendering
+//!
+//! Builds AthleteNFT's fully on-chain `tokenURI`: an ERC-721 metadata JSON
+//! document with an SVG player card as its image, both base64-encoded into
+//! `data:` URIs so the profile renders without any off-chain hosting.
+//!
+//! ## Notes:
+//! - User-supplied text (the athlete name) is escaped for JSON and for XML
+//! - Stat bars are 0-100 stats scaled to a 200px track
+//! - Pure string building, no storage access
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Standard base64 alphabet (RFC 4648)
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Width of a stat bar at 100
+const STAT_BAR_WIDTH: u64 = 200;
+
+/// Everything shown on a profile's metadata and card
+pub struct AthleteCard<'a> {
+    pub token_id: U256,
+    pub name: &'a str,
+    pub power: U256,
+    pub speed: U256,
+    pub accuracy: U256,
+    pub matches_played: U256,
+    pub total_runs: U256,
+    pub total_wickets: U256,
+    pub highest_score: U256,
+    pub best_bowling: U256,
+    pub visibility_level: U256,
+    /// Off-chain page for the profile, if a base URI is configured
+    pub external_url: Option<String>,
+}
+
+impl AthleteCard<'_> {
+    /// `data:application/json;base64,...` token URI
+    pub fn to_token_uri(&self) -> String {
+        format!(
+            "data:application/json;base64,{}",
+            base64_encode(self.to_json().as_bytes())
+        )
+    }
+
+    /// ERC-721 metadata JSON document
+    pub fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\"name\":\"{} #{}\",\"description\":\"Living resume of {}: stats update from verified match performance.\",\"image\":\"data:image/svg+xml;base64,{}\"",
+            escape_json(self.name),
+            self.token_id,
+            escape_json(self.name),
+            base64_encode(self.to_svg().as_bytes()),
+        );
+
+        if let Some(url) = &self.external_url {
+            json.push_str(&format!(",\"external_url\":\"{}\"", escape_json(url)));
+        }
+
+        let attributes = [
+            stat_attribute("Power", self.power),
+            stat_attribute("Speed", self.speed),
+            stat_attribute("Accuracy", self.accuracy),
+            number_attribute("Matches Played", self.matches_played),
+            number_attribute("Total Runs", self.total_runs),
+            number_attribute("Total Wickets", self.total_wickets),
+            number_attribute("Highest Score", self.highest_score),
+            number_attribute("Best Bowling", self.best_bowling),
+            number_attribute("Visibility Level", self.visibility_level),
+        ];
+        json.push_str(&format!(",\"attributes\":[{}]}}", attributes.join(",")));
+
+        json
+    }
+
+    /// SVG player card
+    pub fn to_svg(&self) -> String {
+        let name = escape_xml(self.name);
+
+        let mut svg = String::from(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"350\" height=\"500\" viewBox=\"0 0 350 500\" font-family=\"monospace\">\
+             <rect width=\"350\" height=\"500\" rx=\"20\" fill=\"#0b1d3a\"/>\
+             <rect x=\"10\" y=\"10\" width=\"330\" height=\"480\" rx=\"14\" fill=\"none\" stroke=\"#f5c542\" stroke-width=\"2\"/>",
+        );
+
+        svg.push_str(&format!(
+            "<text x=\"175\" y=\"60\" fill=\"#ffffff\" font-size=\"22\" text-anchor=\"middle\">{}</text>\
+             <text x=\"175\" y=\"88\" fill=\"#f5c542\" font-size=\"14\" text-anchor=\"middle\">ATHLETE #{}</text>",
+            name, self.token_id,
+        ));
+
+        for (i, (label, value)) in [
+            ("POWER", self.power),
+            ("SPEED", self.speed),
+            ("ACCURACY", self.accuracy),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let y = 140 + i * 60;
+            svg.push_str(&format!(
+                "<text x=\"40\" y=\"{}\" fill=\"#ffffff\" font-size=\"14\">{} {}</text>\
+                 <rect x=\"40\" y=\"{}\" width=\"{}\" height=\"12\" rx=\"6\" fill=\"#1f3a66\"/>\
+                 <rect x=\"40\" y=\"{}\" width=\"{}\" height=\"12\" rx=\"6\" fill=\"#f5c542\"/>",
+                y,
+                label,
+                value,
+                y + 10,
+                STAT_BAR_WIDTH,
+                y + 10,
+                bar_width(*value),
+            ));
+        }
+
+        for (i, (label, value)) in [
+            ("Matches", self.matches_played),
+            ("Runs", self.total_runs),
+            ("Wickets", self.total_wickets),
+            ("Highest Score", self.highest_score),
+            ("Best Bowling", self.best_bowling),
+        ]
+        .iter()
+        .enumerate()
+        {
+            svg.push_str(&format!(
+                "<text x=\"40\" y=\"{}\" fill=\"#c9d6ea\" font-size=\"13\">{}: {}</text>",
+                340 + i * 26,
+                label,
+                value,
+            ));
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+/// Standard base64 with padding
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        encoded.push(BASE64_ALPHABET[(triple >> 18) as usize & 0x3f] as char);
+        encoded.push(BASE64_ALPHABET[(triple >> 12) as usize & 0x3f] as char);
+        encoded.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(triple >> 6) as usize & 0x3f] as char
+        } else {
+            '='
+        });
+        encoded.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[triple as usize & 0x3f] as char
+        } else {
+            '='
+        });
+    }
+
+    encoded
+}
+
+/// Escape text for use inside a JSON string literal
+pub fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape text for use inside SVG/XML content
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if (c as u32) < 0x20 => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 0-100 stat attribute
+fn stat_attribute(trait_type: &str, value: U256) -> String {
+    format!(
+        "{{\"trait_type\":\"{}\",\"value\":{},\"max_value\":100}}",
+        trait_type, value
+    )
+}
+
+/// Plain number attribute
+fn number_attribute(trait_type: &str, value: U256) -> String {
+    format!(
+        "{{\"display_type\":\"number\",\"trait_type\":\"{}\",\"value\":{}}}",
+        trait_type, value
+    )
+}
+
+/// Bar width for a 0-100 stat (capped at the full track)
+fn bar_width(stat: U256) -> u64 {
+    let stat = stat.min(U256::from(100)).to::<u64>();
+    stat * STAT_BAR_WIDTH / 100
+}
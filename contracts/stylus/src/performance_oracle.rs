@@ -8,14 +8,26 @@
 //! - Finalize matches with performance data
 //! - Generate cryptographic proofs for match results
 //! - Verify performance claims against stored data
+//! - Pin the RewardTiers config version in force when a match is registered
+//! - Mark matches as overturned (fraud, disputes) so rewards can be clawed back
 
 use stylus_sdk::{
     alloy_primitives::{Address, U256, FixedBytes},
     prelude::*,
     msg,
     block,
+    call::Call,
 };
 
+use crate::fixed_point::{checked_add, mul_div, Rounding};
+
+// Subset of the RewardTiers interface used to pin config versions
+sol_interface! {
+    interface IRewardTiers {
+        function getConfigVersion() external view returns (uint256);
+    }
+}
+
 // Define the match data structure
 sol_storage! {
     /// Main PerformanceOracle contract storage
@@ -35,6 +47,15 @@ sol_storage! {
 
         /// Mapping from player address to their match history
         mapping(address => bytes32[]) player_match_history;
+
+        /// RewardTiers contract address (for config version pinning)
+        address reward_tiers_contract;
+
+        /// Arbiter allowed to overturn match results (besides the owner)
+        address arbiter;
+
+        /// Extended bowling / wearable metrics per performance
+        mapping(bytes32 => mapping(address => ExtendedMetrics)) extended_metrics;
     }
 
     /// Match metadata and status
@@ -46,6 +67,8 @@ sol_storage! {
         bool is_finalized;
         uint8 total_players;
         bytes32 data_hash; // Hash of the complete match data
+        uint256 config_version; // RewardTiers config version pinned at registration
+        bool is_overturned; // Result found invalid after finalization
     }
 
     /// Individual player performance in a match
@@ -60,6 +83,13 @@ sol_storage! {
         uint256 effort_score; // From wearable data (0-100)
         bool verified;
     }
+
+    /// Metrics recorded alongside a performance (optional)
+    pub struct ExtendedMetrics {
+        uint256 runs_conceded;
+        uint256 top_speed; // From wearable data, km/h multiplied by 100 (0 = no reading)
+        bool recorded;
+    }
 }
 
 /// Events emitted by the oracle
@@ -70,12 +100,23 @@ sol! {
         uint256 timestamp
     );
 
+    event MatchConfigPinned(
+        bytes32 indexed matchId,
+        uint256 configVersion
+    );
+
     event MatchFinalized(
         bytes32 indexed matchId,
         uint256 totalPlayers,
         uint256 timestamp
     );
 
+    event MatchOverturned(
+        bytes32 indexed matchId,
+        address indexed arbiter,
+        uint256 timestamp
+    );
+
     event PerformanceRecorded(
         bytes32 indexed matchId,
         address indexed player,
@@ -83,23 +124,55 @@ sol! {
         uint256 effortScore
     );
 
+    event ExtendedMetricsRecorded(
+        bytes32 indexed matchId,
+        address indexed player,
+        uint256 runsConceded,
+        uint256 topSpeed
+    );
+
     error MatchNotFound();
     error MatchAlreadyFinalized();
+    error MatchAlreadyRegistered();
     error MatchNotFinalized();
     error Unauthorized();
     error InvalidPlayer();
+    error InvalidAddress();
+    error MatchAlreadyOverturned();
 }
 
 #[public]
 impl PerformanceOracle {
-    /// Initialize the contract with the owner
-    pub fn init(&mut self) -> Result<(), Vec<u8>> {
+    /// Initialize the contract with the owner and RewardTiers address
+    pub fn init(&mut self, reward_tiers_contract: Address) -> Result<(), Vec<u8>> {
+        if reward_tiers_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
         let caller = msg::sender();
         self.owner.set(caller);
+        self.reward_tiers_contract.set(reward_tiers_contract);
         self.total_matches.set(U256::from(0));
         Ok(())
     }
 
+    /// Set the RewardTiers contract used for version pinning (admin only)
+    pub fn set_reward_tiers_contract(
+        &mut self,
+        reward_tiers_contract: Address,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if reward_tiers_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.reward_tiers_contract.set(reward_tiers_contract);
+        Ok(())
+    }
+
     /// Register a new match before it begins
     /// @param matchId Unique identifier for the match (generated off-chain)
     pub fn register_match(&mut self, match_id: FixedBytes<32>) -> Result<(), Vec<u8>> {
@@ -111,6 +184,15 @@ impl PerformanceOracle {
             return Err(MatchAlreadyFinalized {}.encode());
         }
 
+        // Organizer and pinned config version are fixed once registered
+        if existing_match.registered_at.get() != U256::from(0) {
+            return Err(MatchAlreadyRegistered {}.encode());
+        }
+
+        // Pin the tier config in force right now
+        let reward_tiers = IRewardTiers::new(self.reward_tiers_contract.get());
+        let config_version = reward_tiers.get_config_version(Call::new())?;
+
         // Create new match record
         let mut new_match = self.matches.setter(match_id);
         new_match.match_id.set(match_id);
@@ -118,10 +200,11 @@ impl PerformanceOracle {
         new_match.registered_at.set(U256::from(block::timestamp()));
         new_match.is_finalized.set(false);
         new_match.total_players.set(0);
+        new_match.config_version.set(config_version);
 
         // Increment total matches
         let current_total = self.total_matches.get();
-        self.total_matches.set(current_total + U256::from(1));
+        self.total_matches.set(checked_add(current_total, U256::from(1))?);
 
         // Emit event
         evm::log(MatchRegistered {
@@ -130,6 +213,11 @@ impl PerformanceOracle {
             timestamp: U256::from(block::timestamp()),
         });
 
+        evm::log(MatchConfigPinned {
+            matchId: match_id,
+            configVersion: config_version,
+        });
+
         Ok(())
     }
 
@@ -209,7 +297,7 @@ impl PerformanceOracle {
 
         // Calculate strike rate (runs * 100 / balls_faced)
         let strike_rate = if balls_faced > U256::from(0) {
-            (runs_scored * U256::from(100)) / balls_faced
+            mul_div(runs_scored, U256::from(100), balls_faced, Rounding::Down)?
         } else {
             U256::from(0)
         };
@@ -240,6 +328,99 @@ impl PerformanceOracle {
         Ok(())
     }
 
+    /// Record extended metrics for an already recorded performance
+    /// @param matchId The match identifier
+    /// @param player Player's address
+    /// @param runsConceded Runs conceded while bowling
+    /// @param topSpeed Wearable top running speed, km/h * 100 (0 = no reading)
+    pub fn record_extended_metrics(
+        &mut self,
+        match_id: FixedBytes<32>,
+        player: Address,
+        runs_conceded: U256,
+        top_speed: U256,
+    ) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+
+        // Verify match organizer
+        let match_data = self.matches.get(match_id);
+        if match_data.organizer.get() != caller {
+            return Err(Unauthorized {}.encode());
+        }
+
+        // Recorded with the performance, before finalization
+        if match_data.is_finalized.get() {
+            return Err(MatchAlreadyFinalized {}.encode());
+        }
+
+        if !self.performances.get(match_id).get(player).verified.get() {
+            return Err(InvalidPlayer {}.encode());
+        }
+
+        let mut metrics = self.extended_metrics.setter(match_id).setter(player);
+        metrics.runs_conceded.set(runs_conceded);
+        metrics.top_speed.set(top_speed);
+        metrics.recorded.set(true);
+
+        evm::log(ExtendedMetricsRecorded {
+            matchId: match_id,
+            player,
+            runsConceded: runs_conceded,
+            topSpeed: top_speed,
+        });
+
+        Ok(())
+    }
+
+    /// Set the arbiter allowed to overturn matches (admin only)
+    pub fn set_arbiter(&mut self, arbiter: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.arbiter.set(arbiter);
+        Ok(())
+    }
+
+    /// Mark a finalized match as overturned (owner or arbiter)
+    /// Downstream contracts use this status to claw back rewards.
+    /// @param matchId The match identifier
+    pub fn overturn_match(&mut self, match_id: FixedBytes<32>) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.owner.get() && caller != self.arbiter.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let mut match_data = self.matches.setter(match_id);
+
+        if match_data.registered_at.get() == U256::from(0) {
+            return Err(MatchNotFound {}.encode());
+        }
+
+        if !match_data.is_finalized.get() {
+            return Err(MatchNotFinalized {}.encode());
+        }
+
+        if match_data.is_overturned.get() {
+            return Err(MatchAlreadyOverturned {}.encode());
+        }
+
+        match_data.is_overturned.set(true);
+
+        evm::log(MatchOverturned {
+            matchId: match_id,
+            arbiter: caller,
+            timestamp: U256::from(block::timestamp()),
+        });
+
+        Ok(())
+    }
+
+    /// Check whether a match result has been overturned
+    pub fn is_match_overturned(&self, match_id: FixedBytes<32>) -> Result<bool, Vec<u8>> {
+        Ok(self.matches.get(match_id).is_overturned.get())
+    }
+
     /// Get match data proof (for verification)
     /// @param matchId The match identifier
     /// @return Match data hash and finalization status
@@ -292,6 +473,50 @@ impl PerformanceOracle {
         ))
     }
 
+    /// Get the full performance record, including balls faced and bowled
+    /// @param matchId The match identifier
+    /// @param player Player's address
+    /// @return (runs, wickets, ballsFaced, ballsBowled, strikeRate, tier, effort)
+    pub fn get_performance_details(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<(U256, U256, U256, U256, U256, u8, U256), Vec<u8>> {
+        let perf = self.performances.get(match_id).get(player);
+
+        if !perf.verified.get() {
+            return Err(InvalidPlayer {}.encode());
+        }
+
+        Ok((
+            perf.runs_scored.get(),
+            perf.wickets_taken.get(),
+            perf.balls_faced.get(),
+            perf.balls_bowled.get(),
+            perf.strike_rate.get(),
+            perf.tier.get(),
+            perf.effort_score.get(),
+        ))
+    }
+
+    /// Get extended metrics of a performance (zeros if none were recorded)
+    /// @param matchId The match identifier
+    /// @param player Player's address
+    /// @return (runsConceded, topSpeed, recorded)
+    pub fn get_extended_metrics(
+        &self,
+        match_id: FixedBytes<32>,
+        player: Address,
+    ) -> Result<(U256, U256, bool), Vec<u8>> {
+        let metrics = self.extended_metrics.get(match_id).get(player);
+
+        Ok((
+            metrics.runs_conceded.get(),
+            metrics.top_speed.get(),
+            metrics.recorded.get(),
+        ))
+    }
+
     /// Get total number of matches registered
     pub fn get_total_matches(&self) -> Result<U256, Vec<u8>> {
         Ok(self.total_matches.get())
@@ -314,6 +539,19 @@ impl PerformanceOracle {
         ))
     }
 
+    /// Get the RewardTiers config version pinned when the match was registered
+    /// @param matchId The match identifier
+    /// @return Config version
+    pub fn get_match_config_version(&self, match_id: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        let match_data = self.matches.get(match_id);
+
+        if match_data.registered_at.get() == U256::from(0) {
+            return Err(MatchNotFound {}.encode());
+        }
+
+        Ok(match_data.config_version.get())
+    }
+
     /// Check if caller is the contract owner
     pub fn is_owner(&self) -> Result<bool, Vec<u8>> {
         Ok(self.owner.get() == msg::sender())
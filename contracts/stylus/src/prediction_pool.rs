@@ -0,0 +1,603 @@
+//! # Prediction Pool Contract
+//!
+//! Fan prediction markets on player outcomes, settled by PerformanceOracle.
+//!
+//! ## Market Lifecycle:
+//! - The owner opens a market on a registered, not yet finalized match:
+//!   "player X reaches `threshold` runs / wickets"
+//! - Fans stake SPP on YES or NO until the market's betting deadline, or
+//!   until the player's performance is recorded if that comes first; stakes
+//!   stay locked until the oracle finalizes the match
+//! - Anyone can resolve the market once the match is finalized; the outcome
+//!   is read from `get_player_performance`
+//! - Winners split the losing side pro-rata to their stake, after a protocol
+//!   fee on the losing side that is burned through `SPPToken::burn`
+//! - Markets are void (full refunds, no fee) when the match is overturned,
+//!   the player has no verified record, one side has no stakes, or the
+//!   owner cancels the market before resolution
+
+use stylus_sdk::{
+    alloy_primitives::{Address, U256, FixedBytes},
+    prelude::*,
+    msg,
+    block,
+    contract,
+    call::Call,
+};
+
+use crate::deflatinary_burn::BPS_DENOMINATOR;
+use crate::fixed_point::{checked_add, checked_sub, mul_div, Rounding};
+
+/// Market on runs scored
+pub const METRIC_RUNS: u8 = 0;
+
+/// Market on wickets taken
+pub const METRIC_WICKETS: u8 = 1;
+
+/// Default protocol fee on the losing side (5%)
+pub const DEFAULT_FEE_BPS: u64 = 500;
+
+/// Maximum protocol fee (20%)
+pub const MAX_FEE_BPS: u64 = 2_000;
+
+sol_interface! {
+    interface IPerformanceOracle {
+        function getMatchDetails(bytes32 match_id) external view returns (address, uint256, bool, uint8);
+        function isMatchOverturned(bytes32 match_id) external view returns (bool);
+        function verifyPerformance(bytes32 match_id, address player) external view returns (bool);
+        function getPlayerPerformance(bytes32 match_id, address player) external view returns (uint256, uint256, uint8, uint256);
+    }
+
+    interface ISPPToken {
+        function transfer(address to, uint256 amount) external returns (bool);
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function burn(uint256 amount) external;
+    }
+}
+
+sol_storage! {
+    /// Main PredictionPool contract storage
+    #[entrypoint]
+    pub struct PredictionPool {
+        /// Contract owner (admin)
+        address owner;
+
+        /// SPP Token contract address
+        address token_contract;
+
+        /// PerformanceOracle contract address
+        address oracle_contract;
+
+        /// Protocol fee for new markets (bps of the losing side)
+        uint256 fee_bps;
+
+        /// Market ID counter (IDs start at 1)
+        uint256 next_market_id;
+
+        /// Mapping from market ID to market
+        mapping(uint256 => Market) markets;
+
+        /// Mapping from match ID to its market IDs
+        mapping(bytes32 => uint256[]) match_markets;
+
+        /// Positions (market ID => account => position)
+        mapping(uint256 => mapping(address => Position)) positions;
+
+        /// Total protocol fees burned
+        uint256 total_fees_burned;
+    }
+
+    /// A prediction market
+    pub struct Market {
+        bytes32 match_id;
+        address player;
+        uint8 metric;
+        uint256 threshold;
+        uint256 closes_at; // Betting deadline
+        uint256 fee_bps; // Fixed at creation
+        uint256 yes_pool;
+        uint256 no_pool;
+        uint256 fee_burned;
+        bool resolved;
+        bool voided;
+        bool outcome; // YES won
+        bool exists;
+    }
+
+    /// A fan's stakes in one market
+    pub struct Position {
+        uint256 yes_stake;
+        uint256 no_stake;
+        bool claimed;
+    }
+}
+
+sol! {
+    event MarketCreated(
+        uint256 indexed marketId,
+        bytes32 indexed matchId,
+        address indexed player,
+        uint8 metric,
+        uint256 threshold,
+        uint256 closesAt
+    );
+
+    event PredictionPlaced(
+        uint256 indexed marketId,
+        address indexed account,
+        bool outcome,
+        uint256 amount
+    );
+
+    event MarketResolved(
+        uint256 indexed marketId,
+        bool outcome,
+        uint256 winningPool,
+        uint256 losingPool,
+        uint256 feeBurned
+    );
+
+    event MarketVoided(uint256 indexed marketId);
+
+    event WinningsClaimed(
+        uint256 indexed marketId,
+        address indexed account,
+        uint256 amount
+    );
+
+    event FeeUpdated(uint256 feeBps);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error InvalidMetric();
+    error InvalidFee();
+    error InvalidDeadline();
+    error MatchNotFound();
+    error MatchAlreadyFinalized();
+    error MatchNotFinalized();
+    error MarketNotFound();
+    error BettingClosed();
+    error MarketAlreadySettled();
+    error MarketNotSettled();
+    error AlreadyClaimed();
+    error NothingToClaim();
+    error TokenTransferFailed();
+}
+
+#[public]
+impl PredictionPool {
+    /// Initialize with the token and oracle contracts
+    pub fn init(
+        &mut self,
+        token_contract: Address,
+        oracle_contract: Address,
+    ) -> Result<(), Vec<u8>> {
+        if token_contract == Address::ZERO || oracle_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.owner.set(msg::sender());
+        self.token_contract.set(token_contract);
+        self.oracle_contract.set(oracle_contract);
+        self.fee_bps.set(U256::from(DEFAULT_FEE_BPS));
+        self.next_market_id.set(U256::from(1));
+        Ok(())
+    }
+
+    /// Set the protocol fee for new markets (admin only, max 20%)
+    pub fn set_fee(&mut self, fee_bps: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if fee_bps > U256::from(MAX_FEE_BPS) {
+            return Err(InvalidFee {}.encode());
+        }
+
+        self.fee_bps.set(fee_bps);
+
+        evm::log(FeeUpdated { feeBps: fee_bps });
+
+        Ok(())
+    }
+
+    // ==================== Markets ====================
+
+    /// Open a market: "player reaches `threshold` of `metric`" (admin only)
+    /// @param matchId Registered, not yet finalized oracle match
+    /// @param player Player the market is about
+    /// @param metric METRIC_RUNS or METRIC_WICKETS
+    /// @param threshold YES wins if the metric is >= threshold
+    /// @param closesAt Betting deadline (timestamp)
+    /// @return Market ID
+    pub fn create_market(
+        &mut self,
+        match_id: FixedBytes<32>,
+        player: Address,
+        metric: u8,
+        threshold: U256,
+        closes_at: U256,
+    ) -> Result<U256, Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if player == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        if metric != METRIC_RUNS && metric != METRIC_WICKETS {
+            return Err(InvalidMetric {}.encode());
+        }
+
+        if closes_at <= U256::from(block::timestamp()) {
+            return Err(InvalidDeadline {}.encode());
+        }
+
+        let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+        let (_, registered_at, is_finalized, _) = oracle.get_match_details(Call::new(), match_id)?;
+        if registered_at == U256::from(0) {
+            return Err(MatchNotFound {}.encode());
+        }
+        if is_finalized {
+            return Err(MatchAlreadyFinalized {}.encode());
+        }
+
+        let market_id = self.next_market_id.get();
+        self.next_market_id.set(checked_add(market_id, U256::from(1))?);
+
+        let fee_bps = self.fee_bps.get();
+        let mut market = self.markets.setter(market_id);
+        market.match_id.set(match_id);
+        market.player.set(player);
+        market.metric.set(metric);
+        market.threshold.set(threshold);
+        market.closes_at.set(closes_at);
+        market.fee_bps.set(fee_bps);
+        market.exists.set(true);
+
+        self.match_markets.setter(match_id).push(market_id);
+
+        evm::log(MarketCreated {
+            marketId: market_id,
+            matchId: match_id,
+            player,
+            metric,
+            threshold,
+            closesAt: closes_at,
+        });
+
+        Ok(market_id)
+    }
+
+    /// Stake on an outcome (requires an SPP allowance or permit)
+    /// @param marketId Market identifier
+    /// @param outcome true = YES, false = NO
+    /// @param amount SPP to stake
+    pub fn predict(&mut self, market_id: U256, outcome: bool, amount: U256) -> Result<(), Vec<u8>> {
+        let account = msg::sender();
+
+        if amount == U256::from(0) {
+            return Err(InvalidAmount {}.encode());
+        }
+
+        let (match_id, player, closes_at, yes_pool, no_pool) = {
+            let market = self.markets.get(market_id);
+            if !market.exists.get() {
+                return Err(MarketNotFound {}.encode());
+            }
+            if market.resolved.get() || market.voided.get() {
+                return Err(MarketAlreadySettled {}.encode());
+            }
+            (
+                market.match_id.get(),
+                market.player.get(),
+                market.closes_at.get(),
+                market.yes_pool.get(),
+                market.no_pool.get(),
+            )
+        };
+
+        if U256::from(block::timestamp()) >= closes_at {
+            return Err(BettingClosed {}.encode());
+        }
+
+        // No bets once the result is on-chain, even before the deadline:
+        // the player's record is public as soon as it is recorded
+        let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+        let (_, _, is_finalized, _) = oracle.get_match_details(Call::new(), match_id)?;
+        if is_finalized || oracle.verify_performance(Call::new(), match_id, player)? {
+            return Err(BettingClosed {}.encode());
+        }
+
+        let (yes_stake, no_stake) = {
+            let position = self.positions.get(market_id).get(account);
+            (position.yes_stake.get(), position.no_stake.get())
+        };
+
+        if outcome {
+            self.markets.setter(market_id).yes_pool.set(checked_add(yes_pool, amount)?);
+            self.positions
+                .setter(market_id)
+                .setter(account)
+                .yes_stake
+                .set(checked_add(yes_stake, amount)?);
+        } else {
+            self.markets.setter(market_id).no_pool.set(checked_add(no_pool, amount)?);
+            self.positions
+                .setter(market_id)
+                .setter(account)
+                .no_stake
+                .set(checked_add(no_stake, amount)?);
+        }
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer_from(Call::new_in(self), account, contract::address(), amount)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(PredictionPlaced {
+            marketId: market_id,
+            account,
+            outcome,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Resolve a market from the oracle once its match is finalized (callable by anyone)
+    pub fn resolve_market(&mut self, market_id: U256) -> Result<(), Vec<u8>> {
+        let (match_id, player, metric, threshold, fee_bps, yes_pool, no_pool) = {
+            let market = self.markets.get(market_id);
+            if !market.exists.get() {
+                return Err(MarketNotFound {}.encode());
+            }
+            if market.resolved.get() || market.voided.get() {
+                return Err(MarketAlreadySettled {}.encode());
+            }
+            (
+                market.match_id.get(),
+                market.player.get(),
+                market.metric.get(),
+                market.threshold.get(),
+                market.fee_bps.get(),
+                market.yes_pool.get(),
+                market.no_pool.get(),
+            )
+        };
+
+        let oracle = IPerformanceOracle::new(self.oracle_contract.get());
+        let (_, _, is_finalized, _) = oracle.get_match_details(Call::new(), match_id)?;
+        if !is_finalized {
+            return Err(MatchNotFinalized {}.encode());
+        }
+
+        if oracle.is_match_overturned(Call::new(), match_id)?
+            || !oracle.verify_performance(Call::new(), match_id, player)?
+        {
+            return self._void(market_id);
+        }
+
+        let (runs, wickets, _, _) = oracle.get_player_performance(Call::new(), match_id, player)?;
+        let value = if metric == METRIC_RUNS { runs } else { wickets };
+        let outcome = value >= threshold;
+
+        let (winning_pool, losing_pool) = if outcome {
+            (yes_pool, no_pool)
+        } else {
+            (no_pool, yes_pool)
+        };
+
+        // Nobody to pay or nobody to pay from: refund everyone
+        if winning_pool == U256::from(0) || losing_pool == U256::from(0) {
+            return self._void(market_id);
+        }
+
+        let fee = settlement_fee(losing_pool, fee_bps)?;
+
+        let mut market = self.markets.setter(market_id);
+        market.resolved.set(true);
+        market.outcome.set(outcome);
+        market.fee_burned.set(fee);
+
+        if fee > U256::from(0) {
+            let total_fees = self.total_fees_burned.get();
+            self.total_fees_burned.set(checked_add(total_fees, fee)?);
+
+            let token = ISPPToken::new(self.token_contract.get());
+            token.burn(Call::new_in(self), fee)?;
+        }
+
+        evm::log(MarketResolved {
+            marketId: market_id,
+            outcome,
+            winningPool: winning_pool,
+            losingPool: losing_pool,
+            feeBurned: fee,
+        });
+
+        Ok(())
+    }
+
+    /// Void a market before resolution, e.g. an abandoned match (admin only)
+    pub fn void_market(&mut self, market_id: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self._void(market_id)
+    }
+
+    /// Claim winnings of a resolved market, or a refund of a void one
+    /// @return Amount paid out
+    pub fn claim(&mut self, market_id: U256) -> Result<U256, Vec<u8>> {
+        let account = msg::sender();
+
+        let payout = self._payout(market_id, account)?;
+        if self.positions.get(market_id).get(account).claimed.get() {
+            return Err(AlreadyClaimed {}.encode());
+        }
+        if payout == U256::from(0) {
+            return Err(NothingToClaim {}.encode());
+        }
+
+        self.positions.setter(market_id).setter(account).claimed.set(true);
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer(Call::new_in(self), account, payout)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(WinningsClaimed {
+            marketId: market_id,
+            account,
+            amount: payout,
+        });
+
+        Ok(payout)
+    }
+
+    // ==================== View Functions ====================
+
+    /// Get market details
+    /// @return (matchId, player, metric, threshold, closesAt, yesPool, noPool, resolved, voided, outcome)
+    pub fn get_market(
+        &self,
+        market_id: U256,
+    ) -> Result<(FixedBytes<32>, Address, u8, U256, U256, U256, U256, bool, bool, bool), Vec<u8>> {
+        let market = self.markets.get(market_id);
+        if !market.exists.get() {
+            return Err(MarketNotFound {}.encode());
+        }
+
+        Ok((
+            market.match_id.get(),
+            market.player.get(),
+            market.metric.get(),
+            market.threshold.get(),
+            market.closes_at.get(),
+            market.yes_pool.get(),
+            market.no_pool.get(),
+            market.resolved.get(),
+            market.voided.get(),
+            market.outcome.get(),
+        ))
+    }
+
+    /// Get all market IDs of a match
+    pub fn get_match_markets(&self, match_id: FixedBytes<32>) -> Result<Vec<U256>, Vec<u8>> {
+        let ids = self.match_markets.get(match_id);
+        let mut result = Vec::with_capacity(ids.len());
+        for i in 0..ids.len() {
+            result.push(ids.get(i).unwrap_or_default());
+        }
+        Ok(result)
+    }
+
+    /// Get a fan's position
+    /// @return (yesStake, noStake, claimed)
+    pub fn get_position(
+        &self,
+        market_id: U256,
+        account: Address,
+    ) -> Result<(U256, U256, bool), Vec<u8>> {
+        let position = self.positions.get(market_id).get(account);
+        Ok((
+            position.yes_stake.get(),
+            position.no_stake.get(),
+            position.claimed.get(),
+        ))
+    }
+
+    /// Get what a fan can claim from a settled market (zero once claimed)
+    pub fn claimable_amount(&self, market_id: U256, account: Address) -> Result<U256, Vec<u8>> {
+        if self.positions.get(market_id).get(account).claimed.get() {
+            return Ok(U256::from(0));
+        }
+        self._payout(market_id, account)
+    }
+
+    /// Get the protocol fee for new markets (bps)
+    pub fn get_fee(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.fee_bps.get())
+    }
+
+    /// Get total protocol fees burned
+    pub fn total_fees_burned(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_fees_burned.get())
+    }
+}
+
+// ==================== Internal Functions ====================
+
+impl PredictionPool {
+    /// Mark an open market void so every stake is refunded
+    /// A resolved market has already burned its fee, so it can't be voided.
+    fn _void(&mut self, market_id: U256) -> Result<(), Vec<u8>> {
+        let mut market = self.markets.setter(market_id);
+        if !market.exists.get() {
+            return Err(MarketNotFound {}.encode());
+        }
+        if market.resolved.get() || market.voided.get() {
+            return Err(MarketAlreadySettled {}.encode());
+        }
+        market.voided.set(true);
+
+        evm::log(MarketVoided { marketId: market_id });
+
+        Ok(())
+    }
+
+    /// Payout of a settled market for an account (ignores the claimed flag)
+    /// winner: stake + stake * (losingPool - fee) / winningPool
+    fn _payout(&self, market_id: U256, account: Address) -> Result<U256, Vec<u8>> {
+        let market = self.markets.get(market_id);
+        if !market.exists.get() {
+            return Err(MarketNotFound {}.encode());
+        }
+
+        let position = self.positions.get(market_id).get(account);
+        let (yes_stake, no_stake) = (position.yes_stake.get(), position.no_stake.get());
+
+        if market.voided.get() {
+            return checked_add(yes_stake, no_stake);
+        }
+
+        if !market.resolved.get() {
+            return Err(MarketNotSettled {}.encode());
+        }
+
+        let (stake, winning_pool, losing_pool) = if market.outcome.get() {
+            (yes_stake, market.yes_pool.get(), market.no_pool.get())
+        } else {
+            (no_stake, market.no_pool.get(), market.yes_pool.get())
+        };
+
+        if stake == U256::from(0) {
+            return Ok(U256::from(0));
+        }
+
+        winning_payout(stake, winning_pool, losing_pool, market.fee_burned.get())
+    }
+}
+
+// ==================== Settlement Math Helpers ====================
+
+/// Fee burned from the losing pool at resolution (rounds down)
+pub fn settlement_fee(losing_pool: U256, fee_bps: U256) -> Result<U256, Vec<u8>> {
+    mul_div(losing_pool, fee_bps, U256::from(BPS_DENOMINATOR), Rounding::Down)
+}
+
+/// A winning stake plus its pro-rata share of the losing pool after the
+/// fee (rounds down, so payouts never exceed the pools)
+pub fn winning_payout(
+    stake: U256,
+    winning_pool: U256,
+    losing_pool: U256,
+    fee: U256,
+) -> Result<U256, Vec<u8>> {
+    let distributable = checked_sub(losing_pool, fee)?;
+    checked_add(stake, mul_div(stake, distributable, winning_pool, Rounding::Down)?)
+}
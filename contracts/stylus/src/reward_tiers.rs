@@ -6,16 +6,26 @@
 //!
 //! ## Tier System:
 //! - 8 predefined tiers based on cricket achievements
-//! - Each tier has a multiplier and base reward
-//! - Admin can update tier values for flexibility
-//! - Read-only access for other contracts
+//! - Each tier has a multiplier and base reward (in SPPToken base units)
+//! - Admin can schedule tier value changes behind a timelock; shortening
+//!   the timelock itself only takes effect after the current delay
+//! - Active values are resolved by `block::timestamp()`, and past values stay
+//!   queryable so rewards can use the config in force at match registration
+//! - Every scheduled change bumps the config version; a version is an
+//!   immutable snapshot once in force and can be pinned by other contracts
+//! - Read-only access for other contracts (DeflatinaryBurn reads its
+//!   multipliers and base rewards from here)
 
 use stylus_sdk::{
     alloy_primitives::{Address, U256},
     prelude::*,
     msg,
+    block,
+    storage::{StorageU256, StorageVec},
 };
 
+use crate::fixed_point::{checked_add, to_base_units};
+
 // Tier constants
 pub const TIER_NIFTY_FIFTY: u8 = 0;
 pub const TIER_GAYLE_STORM: u8 = 1;
@@ -26,6 +36,12 @@ pub const TIER_RUN_MACHINE: u8 = 5;
 pub const TIER_GOLDEN_ARM: u8 = 6;
 pub const TIER_ALL_ROUNDER: u8 = 7;
 
+/// Default minimum delay between scheduling and activating a tier change (2 days)
+pub const DEFAULT_MIN_DELAY: u64 = 2 * 24 * 60 * 60;
+
+/// Max changes queued and not yet in force (bounds cancellation)
+pub const MAX_PENDING_CHANGES: usize = 16;
+
 sol_storage! {
     /// Main RewardTiers contract storage
     #[entrypoint]
@@ -38,6 +54,36 @@ sol_storage! {
 
         /// Total number of tiers
         uint8 total_tiers;
+
+        /// Minimum delay (seconds) between scheduling and activating a change
+        uint256 min_delay;
+
+        /// Shorter delay waiting out the current one (at = 0: none pending)
+        uint256 pending_min_delay;
+        uint256 pending_min_delay_at;
+
+        /// Scheduled tier changes by change ID (IDs start at 1).
+        /// The change ID doubles as the config version it introduces;
+        /// version 0 is the initial configuration.
+        mapping(uint256 => TierChange) tier_changes;
+
+        /// Number of tier changes ever scheduled (latest config version)
+        uint256 tier_change_count;
+
+        /// Non-cancelled change IDs, in scheduling (= activation) order
+        uint256[] active_change_ids;
+
+        /// Non-cancelled change IDs affecting each tier, in scheduling order
+        mapping(uint8 => uint256[]) tier_change_ids;
+    }
+
+    /// A queued change to a tier's economics
+    pub struct TierChange {
+        uint8 tier_id;
+        uint256 multiplier; // Multiplied by 10 (e.g., 15 = 1.5x)
+        uint256 base_reward; // Token base units (18 decimals)
+        uint256 effective_at;
+        bool cancelled;
     }
 
     /// Tier configuration
@@ -45,8 +91,8 @@ sol_storage! {
         uint8 tier_id;
         string name;
         string description;
-        uint256 multiplier; // Multiplied by 10 (e.g., 15 = 1.5x)
-        uint256 base_reward;
+        uint256 multiplier; // Initial value, multiplied by 10 (e.g., 15 = 1.5x)
+        uint256 base_reward; // Initial value in token base units, superseded by active TierChanges
         uint256 min_runs; // Minimum runs for batting tiers
         uint256 min_wickets; // Minimum wickets for bowling tiers
         bool is_active;
@@ -61,17 +107,31 @@ sol! {
         uint256 baseReward
     );
 
-    event TierUpdated(
+    event TierUpdateScheduled(
+        uint256 indexed changeId,
         uint8 indexed tierId,
         uint256 newMultiplier,
-        uint256 newBaseReward
+        uint256 newBaseReward,
+        uint256 effectiveAt
     );
 
+    event TierUpdateCancelled(uint256 indexed changeId, uint8 indexed tierId);
+
+    event MinDelayUpdated(uint256 previousDelay, uint256 newDelay);
+
+    event MinDelayUpdateScheduled(uint256 newDelay, uint256 effectiveAt);
+
     event TierActivated(uint8 indexed tierId, bool active);
 
     error InvalidTier();
     error Unauthorized();
     error TierNotActive();
+    error EffectiveTimeTooEarly();
+    error ChangeNotFound();
+    error ChangeAlreadyActive();
+    error ChangeAlreadyCancelled();
+    error InvalidVersion();
+    error TooManyPendingChanges();
 }
 
 #[public]
@@ -81,6 +141,7 @@ impl RewardTiers {
         let caller = msg::sender();
         self.owner.set(caller);
         self.total_tiers.set(8);
+        self.min_delay.set(U256::from(DEFAULT_MIN_DELAY));
 
         // Configure all 8 tiers
         self._configure_tier(
@@ -166,7 +227,7 @@ impl RewardTiers {
         Ok(())
     }
 
-    /// Get tier multiplier and base reward
+    /// Get tier multiplier and base reward currently in force
     /// @param tierId Tier identifier (0-7)
     /// @return (multiplier, baseReward)
     pub fn get_tier_multiplier(&self, tier_id: u8) -> Result<(U256, U256), Vec<u8>> {
@@ -174,13 +235,75 @@ impl RewardTiers {
             return Err(InvalidTier {}.encode());
         }
 
-        let tier = self.tiers.get(tier_id);
-
-        if !tier.is_active.get() {
+        if !self.tiers.get(tier_id).is_active.get() {
             return Err(TierNotActive {}.encode());
         }
 
-        Ok((tier.multiplier.get(), tier.base_reward.get()))
+        Ok(self._tier_values_at_version(tier_id, self._version_at(U256::from(block::timestamp()))))
+    }
+
+    /// Get the economic parameters of a tier currently in force, including inactive ones
+    /// @param tierId Tier identifier (0-7)
+    /// @return (multiplier, baseReward, isActive)
+    pub fn get_tier_config(&self, tier_id: u8) -> Result<(U256, U256, bool), Vec<u8>> {
+        self.get_tier_config_at(tier_id, U256::from(block::timestamp()))
+    }
+
+    /// Get the economic parameters of a tier as they were in force at a timestamp
+    /// @param tierId Tier identifier (0-7)
+    /// @param timestamp Point in time to resolve the config for
+    /// @return (multiplier, baseReward, isActive) - active status is always the current one
+    pub fn get_tier_config_at(
+        &self,
+        tier_id: u8,
+        timestamp: U256,
+    ) -> Result<(U256, U256, bool), Vec<u8>> {
+        if tier_id >= self.total_tiers.get() {
+            return Err(InvalidTier {}.encode());
+        }
+
+        let (multiplier, base_reward) =
+            self._tier_values_at_version(tier_id, self._version_at(timestamp));
+
+        Ok((multiplier, base_reward, self.tiers.get(tier_id).is_active.get()))
+    }
+
+    /// Get the config version in force right now (0 = initial configuration)
+    pub fn get_config_version(&self) -> Result<U256, Vec<u8>> {
+        Ok(self._version_at(U256::from(block::timestamp())))
+    }
+
+    /// Get the config version that was in force at a timestamp
+    /// @param timestamp Point in time to resolve the version for
+    pub fn get_config_version_at(&self, timestamp: U256) -> Result<U256, Vec<u8>> {
+        Ok(self._version_at(timestamp))
+    }
+
+    /// Get the economic parameters of a tier as of a config version
+    /// @param tierId Tier identifier (0-7)
+    /// @param version Config version (0 = initial configuration)
+    /// @return (multiplier, baseReward, isActive) - active status is always the current one
+    pub fn get_tier_at_version(
+        &self,
+        tier_id: u8,
+        version: U256,
+    ) -> Result<(U256, U256, bool), Vec<u8>> {
+        if tier_id >= self.total_tiers.get() {
+            return Err(InvalidTier {}.encode());
+        }
+
+        if version > self.tier_change_count.get() {
+            return Err(InvalidVersion {}.encode());
+        }
+
+        // A cancelled change never became a snapshot
+        if version > U256::from(0) && self.tier_changes.get(version).cancelled.get() {
+            return Err(InvalidVersion {}.encode());
+        }
+
+        let (multiplier, base_reward) = self._tier_values_at_version(tier_id, version);
+
+        Ok((multiplier, base_reward, self.tiers.get(tier_id).is_active.get()))
     }
 
     /// Get tier details
@@ -194,13 +317,15 @@ impl RewardTiers {
             return Err(InvalidTier {}.encode());
         }
 
+        let (multiplier, base_reward) =
+            self._tier_values_at_version(tier_id, self._version_at(U256::from(block::timestamp())));
         let tier = self.tiers.get(tier_id);
 
         Ok((
             tier.name.get_string(),
             tier.description.get_string(),
-            tier.multiplier.get(),
-            tier.base_reward.get(),
+            multiplier,
+            base_reward,
             tier.is_active.get(),
         ))
     }
@@ -218,16 +343,21 @@ impl RewardTiers {
         Ok((tier.min_runs.get(), tier.min_wickets.get()))
     }
 
-    /// Update tier multiplier and base reward (admin only)
+    /// Schedule a tier multiplier and base reward change (admin only)
+    /// Changes activate in the order they were scheduled, so `effectiveAt` may not
+    /// precede the activation time of any earlier change that is still queued.
     /// @param tierId Tier to update
     /// @param multiplier New multiplier (multiplied by 10)
-    /// @param baseReward New base reward
-    pub fn update_tier(
+    /// @param baseReward New base reward in token base units (18 decimals)
+    /// @param effectiveAt Timestamp at which the change takes effect (>= now + minDelay)
+    /// @return Change ID, which is also the new config version (usable with cancelTierUpdate)
+    pub fn schedule_tier_update(
         &mut self,
         tier_id: u8,
         multiplier: U256,
         base_reward: U256,
-    ) -> Result<(), Vec<u8>> {
+        effective_at: U256,
+    ) -> Result<U256, Vec<u8>> {
         if msg::sender() != self.owner.get() {
             return Err(Unauthorized {}.encode());
         }
@@ -236,19 +366,154 @@ impl RewardTiers {
             return Err(InvalidTier {}.encode());
         }
 
-        let mut tier = self.tiers.setter(tier_id);
-        tier.multiplier.set(multiplier);
-        tier.base_reward.set(base_reward);
+        let earliest = checked_add(U256::from(block::timestamp()), self._min_delay())?;
+        if effective_at < earliest || effective_at < self._latest_effective_at() {
+            return Err(EffectiveTimeTooEarly {}.encode());
+        }
 
-        evm::log(TierUpdated {
+        if self._pending_change_count() >= MAX_PENDING_CHANGES {
+            return Err(TooManyPendingChanges {}.encode());
+        }
+
+        let change_id = checked_add(self.tier_change_count.get(), U256::from(1))?;
+        self.tier_change_count.set(change_id);
+
+        let mut change = self.tier_changes.setter(change_id);
+        change.tier_id.set(tier_id);
+        change.multiplier.set(multiplier);
+        change.base_reward.set(base_reward);
+        change.effective_at.set(effective_at);
+        change.cancelled.set(false);
+
+        self.active_change_ids.push(change_id);
+        self.tier_change_ids.setter(tier_id).push(change_id);
+
+        evm::log(TierUpdateScheduled {
+            changeId: change_id,
             tierId: tier_id,
             newMultiplier: multiplier,
             newBaseReward: base_reward,
+            effectiveAt: effective_at,
+        });
+
+        Ok(change_id)
+    }
+
+    /// Cancel a scheduled tier change before it takes effect (admin only)
+    /// @param changeId Change to cancel
+    pub fn cancel_tier_update(&mut self, change_id: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if change_id == U256::from(0) || change_id > self.tier_change_count.get() {
+            return Err(ChangeNotFound {}.encode());
+        }
+
+        let mut change = self.tier_changes.setter(change_id);
+
+        if change.cancelled.get() {
+            return Err(ChangeAlreadyCancelled {}.encode());
+        }
+
+        if change.effective_at.get() <= U256::from(block::timestamp()) {
+            return Err(ChangeAlreadyActive {}.encode());
+        }
+
+        change.cancelled.set(true);
+        let tier_id = change.tier_id.get();
+
+        // Pending changes sit at the tail, so removal is bounded
+        remove_change_id(&mut self.active_change_ids, change_id);
+        remove_change_id(&mut self.tier_change_ids.setter(tier_id), change_id);
+
+        evm::log(TierUpdateCancelled {
+            changeId: change_id,
+            tierId: tier_id,
+        });
+
+        Ok(())
+    }
+
+    /// Get a scheduled tier change
+    /// @param changeId Change identifier
+    /// @return (tierId, multiplier, baseReward, effectiveAt, cancelled)
+    pub fn get_tier_change(
+        &self,
+        change_id: U256,
+    ) -> Result<(u8, U256, U256, U256, bool), Vec<u8>> {
+        if change_id == U256::from(0) || change_id > self.tier_change_count.get() {
+            return Err(ChangeNotFound {}.encode());
+        }
+
+        let change = self.tier_changes.get(change_id);
+
+        Ok((
+            change.tier_id.get(),
+            change.multiplier.get(),
+            change.base_reward.get(),
+            change.effective_at.get(),
+            change.cancelled.get(),
+        ))
+    }
+
+    /// Get number of tier changes ever scheduled
+    pub fn get_tier_change_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.tier_change_count.get())
+    }
+
+    /// Set the minimum timelock delay for tier changes (admin only)
+    /// A longer delay applies immediately; a shorter one is itself timelocked
+    /// and applies once the current delay has passed, so the timelock can't
+    /// be bypassed by dropping it right before scheduling a change.
+    /// Only affects changes scheduled afterwards.
+    /// @param delay New minimum delay in seconds
+    pub fn set_min_delay(&mut self, delay: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let previous = self._min_delay();
+
+        if delay >= previous {
+            self.min_delay.set(delay);
+            self.pending_min_delay.set(U256::from(0));
+            self.pending_min_delay_at.set(U256::from(0));
+
+            evm::log(MinDelayUpdated {
+                previousDelay: previous,
+                newDelay: delay,
+            });
+
+            return Ok(());
+        }
+
+        // Whatever was pending has matured or is superseded
+        self.min_delay.set(previous);
+
+        let effective_at = checked_add(U256::from(block::timestamp()), previous)?;
+        self.pending_min_delay.set(delay);
+        self.pending_min_delay_at.set(effective_at);
+
+        evm::log(MinDelayUpdateScheduled {
+            newDelay: delay,
+            effectiveAt: effective_at,
         });
 
         Ok(())
     }
 
+    /// Get the minimum timelock delay for tier changes currently in force
+    pub fn get_min_delay(&self) -> Result<U256, Vec<u8>> {
+        Ok(self._min_delay())
+    }
+
+    /// Get a scheduled shorter delay
+    /// @return (delay, effectiveAt) - effectiveAt is 0 when nothing is pending
+    pub fn get_pending_min_delay(&self) -> Result<(U256, U256), Vec<u8>> {
+        Ok((self.pending_min_delay.get(), self.pending_min_delay_at.get()))
+    }
+
     /// Activate or deactivate a tier (admin only)
     /// @param tierId Tier to update
     /// @param active New status
@@ -298,10 +563,77 @@ impl RewardTiers {
 
         Ok(self.tiers.get(tier_id).name.get_string())
     }
+}
 
-    // ==================== Internal Functions ====================
+// ==================== Internal Functions ====================
+
+impl RewardTiers {
+    /// Minimum delay in force, including a scheduled shorter delay once due
+    fn _min_delay(&self) -> U256 {
+        let pending_at = self.pending_min_delay_at.get();
+        if pending_at > U256::from(0) && U256::from(block::timestamp()) >= pending_at {
+            return self.pending_min_delay.get();
+        }
+
+        self.min_delay.get()
+    }
+
+    /// Resolve (multiplier, baseReward) for a tier as of a config version: the
+    /// latest non-cancelled change to the tier at or below that version, or
+    /// the initial configuration
+    fn _tier_values_at_version(&self, tier_id: u8, version: U256) -> (U256, U256) {
+        let change_ids = self.tier_change_ids.get(tier_id);
+        let count = partition_point(change_ids.len(), |i| {
+            change_ids.get(i).unwrap_or_default() <= version
+        });
+
+        if count > 0 {
+            let change = self.tier_changes.get(change_ids.get(count - 1).unwrap_or_default());
+            return (change.multiplier.get(), change.base_reward.get());
+        }
+
+        let tier = self.tiers.get(tier_id);
+        (tier.multiplier.get(), tier.base_reward.get())
+    }
+
+    /// Config version in force at a timestamp. Changes activate in scheduling
+    /// order, so this is the newest non-cancelled change already effective.
+    fn _version_at(&self, timestamp: U256) -> U256 {
+        let count = self._version_index_at(timestamp, self.active_change_ids.len());
+        if count == 0 {
+            return U256::from(0);
+        }
+
+        self.active_change_ids.get(count - 1).unwrap_or_default()
+    }
+
+    /// Activation time of the most recently scheduled non-cancelled change
+    fn _latest_effective_at(&self) -> U256 {
+        let len = self.active_change_ids.len();
+        if len == 0 {
+            return U256::from(0);
+        }
+
+        let change_id = self.active_change_ids.get(len - 1).unwrap_or_default();
+        self.tier_changes.get(change_id).effective_at.get()
+    }
+
+    /// Number of non-cancelled changes not yet in force
+    fn _pending_change_count(&self) -> usize {
+        let len = self.active_change_ids.len();
+        len - self._version_index_at(U256::from(block::timestamp()), len)
+    }
+
+    /// Number of non-cancelled changes in force at a timestamp (binary search)
+    fn _version_index_at(&self, timestamp: U256, len: usize) -> usize {
+        partition_point(len, |i| {
+            let change_id = self.active_change_ids.get(i).unwrap_or_default();
+            self.tier_changes.get(change_id).effective_at.get() <= timestamp
+        })
+    }
 
     /// Internal function to configure a tier
+    /// `base_reward` is given in whole tokens and stored in base units
     fn _configure_tier(
         &mut self,
         tier_id: u8,
@@ -312,13 +644,14 @@ impl RewardTiers {
         min_runs: u64,
         min_wickets: u64,
     ) -> Result<(), Vec<u8>> {
+        let base_reward = to_base_units(U256::from(base_reward))?;
         let mut tier = self.tiers.setter(tier_id);
 
         tier.tier_id.set(tier_id);
         tier.name.set_str(name);
         tier.description.set_str(description);
         tier.multiplier.set(U256::from(multiplier));
-        tier.base_reward.set(U256::from(base_reward));
+        tier.base_reward.set(base_reward);
         tier.min_runs.set(U256::from(min_runs));
         tier.min_wickets.set(U256::from(min_wickets));
         tier.is_active.set(true);
@@ -327,9 +660,47 @@ impl RewardTiers {
             tierId: tier_id,
             name: name.to_string(),
             multiplier: U256::from(multiplier),
-            baseReward: U256::from(base_reward),
+            baseReward: base_reward,
         });
 
         Ok(())
     }
 }
+
+// ==================== Change List Helpers ====================
+
+/// Number of leading entries of a sorted list for which `pred` holds
+/// (binary search; `pred` must be true for a prefix and false after it)
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = (low + high) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Remove a change ID from an ordered list, searching from the tail
+fn remove_change_id(list: &mut StorageVec<StorageU256>, change_id: U256) {
+    let len = list.len();
+    let mut i = len;
+    while i > 0 {
+        i -= 1;
+        if list.get(i).unwrap_or_default() != change_id {
+            continue;
+        }
+
+        for j in i..len - 1 {
+            let next = list.get(j + 1).unwrap_or_default();
+            if let Some(mut slot) = list.setter(j) {
+                slot.set(next);
+            }
+        }
+        list.truncate(len - 1);
+        return;
+    }
+}
@@ -0,0 +1,372 @@
+//! # Reward Vesting Contract
+//!
+//! Optional vesting layer for athlete payouts on top of SPPToken.
+//! DeflatinaryBurn transfers a player's reward here and opens a grant
+//! instead of paying it out immediately.
+//!
+//! ## Vesting Schedules:
+//! - Cliff: nothing vests before the cliff, everything vests at the cliff
+//! - Linear: nothing vests before the cliff, then vesting is linear from
+//!   the grant start until `start + duration`
+//! - Grants can be revoked (e.g. when a match result is overturned); the
+//!   unvested part is returned and the vested part stays releasable
+
+use stylus_sdk::{
+    alloy_primitives::{Address, U256},
+    prelude::*,
+    msg,
+    block,
+    contract,
+    call::Call,
+};
+
+use crate::fixed_point::{checked_add, checked_sub, mul_div, Rounding};
+
+sol_interface! {
+    interface ISPPToken {
+        function transfer(address to, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+sol_storage! {
+    /// Main RewardVesting contract storage
+    #[entrypoint]
+    pub struct RewardVesting {
+        /// Contract owner (admin)
+        address owner;
+
+        /// SPP Token contract address
+        address token_contract;
+
+        /// Contract allowed to open and revoke grants (DeflatinaryBurn)
+        address grant_creator;
+
+        /// Grant ID counter (IDs start at 1)
+        uint256 next_grant_id;
+
+        /// Mapping from grant ID to grant
+        mapping(uint256 => VestingGrant) grants;
+
+        /// Mapping from beneficiary to their grant IDs
+        mapping(address => uint256[]) beneficiary_grants;
+
+        /// Tokens held for grants and not yet released or returned
+        uint256 total_locked;
+    }
+
+    /// A single vesting grant
+    pub struct VestingGrant {
+        address beneficiary;
+        uint256 total_amount; // Reduced to the vested amount on revoke
+        uint256 released;
+        uint256 start;
+        uint256 cliff; // Seconds after start
+        uint256 duration; // Seconds after start (linear grants)
+        bool linear;
+        bool revoked;
+    }
+}
+
+sol! {
+    event GrantCreated(
+        uint256 indexed grantId,
+        address indexed beneficiary,
+        uint256 amount,
+        uint256 cliff,
+        uint256 duration,
+        bool linear
+    );
+
+    event TokensReleased(
+        uint256 indexed grantId,
+        address indexed beneficiary,
+        uint256 amount
+    );
+
+    event GrantRevoked(
+        uint256 indexed grantId,
+        address indexed recipient,
+        uint256 unvestedAmount
+    );
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidSchedule();
+    error GrantNotFound();
+    error GrantAlreadyRevoked();
+    error GrantNotFunded();
+    error NothingToRelease();
+    error TokenTransferFailed();
+}
+
+#[public]
+impl RewardVesting {
+    /// Initialize with the token and the contract allowed to create grants
+    pub fn init(&mut self, token_contract: Address, grant_creator: Address) -> Result<(), Vec<u8>> {
+        if token_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.owner.set(msg::sender());
+        self.token_contract.set(token_contract);
+        self.grant_creator.set(grant_creator);
+        self.next_grant_id.set(U256::from(1));
+        self.total_locked.set(U256::from(0));
+        Ok(())
+    }
+
+    /// Set the contract allowed to create and revoke grants (admin only)
+    pub fn set_grant_creator(&mut self, grant_creator: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.grant_creator.set(grant_creator);
+        Ok(())
+    }
+
+    /// Open a grant for tokens already transferred to this contract
+    /// @param beneficiary Grant beneficiary
+    /// @param amount Tokens to vest
+    /// @param cliff Seconds after now before anything vests
+    /// @param duration Seconds after now until fully vested (linear only)
+    /// @param linear Linear after the cliff, or everything at the cliff
+    /// @return Grant ID
+    pub fn create_grant(
+        &mut self,
+        beneficiary: Address,
+        amount: U256,
+        cliff: U256,
+        duration: U256,
+        linear: bool,
+    ) -> Result<U256, Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.grant_creator.get() && caller != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if beneficiary == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        if linear && (duration == U256::from(0) || cliff > duration) {
+            return Err(InvalidSchedule {}.encode());
+        }
+
+        // The tokens must already be here and not backing another grant
+        let token = ISPPToken::new(self.token_contract.get());
+        let balance = token.balance_of(Call::new(), contract::address())?;
+        let locked = checked_add(self.total_locked.get(), amount)?;
+        if balance < locked {
+            return Err(GrantNotFunded {}.encode());
+        }
+        self.total_locked.set(locked);
+
+        let grant_id = self.next_grant_id.get();
+        self.next_grant_id.set(checked_add(grant_id, U256::from(1))?);
+
+        let mut grant = self.grants.setter(grant_id);
+        grant.beneficiary.set(beneficiary);
+        grant.total_amount.set(amount);
+        grant.released.set(U256::from(0));
+        grant.start.set(U256::from(block::timestamp()));
+        grant.cliff.set(cliff);
+        grant.duration.set(duration);
+        grant.linear.set(linear);
+        grant.revoked.set(false);
+
+        self.beneficiary_grants.setter(beneficiary).push(grant_id);
+
+        evm::log(GrantCreated {
+            grantId: grant_id,
+            beneficiary,
+            amount,
+            cliff,
+            duration,
+            linear,
+        });
+
+        Ok(grant_id)
+    }
+
+    /// Release vested tokens of a grant to its beneficiary (callable by anyone)
+    /// @param grantId Grant identifier
+    /// @return Amount released
+    pub fn release(&mut self, grant_id: U256) -> Result<U256, Vec<u8>> {
+        let (beneficiary, released) = {
+            let grant = self.grants.get(grant_id);
+            if grant.beneficiary.get() == Address::ZERO {
+                return Err(GrantNotFound {}.encode());
+            }
+            (grant.beneficiary.get(), grant.released.get())
+        };
+
+        let vested = self._vested_amount(grant_id, U256::from(block::timestamp()))?;
+        let amount = checked_sub(vested, released)?;
+        if amount == U256::from(0) {
+            return Err(NothingToRelease {}.encode());
+        }
+
+        self.grants.setter(grant_id).released.set(checked_add(released, amount)?);
+        let locked = self.total_locked.get();
+        self.total_locked.set(checked_sub(locked, amount)?);
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer(Call::new_in(self), beneficiary, amount)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(TokensReleased {
+            grantId: grant_id,
+            beneficiary,
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    /// Revoke a grant, returning the unvested part (owner or grant creator)
+    /// Vested but unreleased tokens stay releasable by the beneficiary.
+    /// @param grantId Grant identifier
+    /// @param recipient Receiver of the unvested tokens
+    /// @return Unvested amount returned
+    pub fn revoke(&mut self, grant_id: U256, recipient: Address) -> Result<U256, Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.grant_creator.get() && caller != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if recipient == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        let total_amount = {
+            let grant = self.grants.get(grant_id);
+            if grant.beneficiary.get() == Address::ZERO {
+                return Err(GrantNotFound {}.encode());
+            }
+            if grant.revoked.get() {
+                return Err(GrantAlreadyRevoked {}.encode());
+            }
+            grant.total_amount.get()
+        };
+
+        let vested = self._vested_amount(grant_id, U256::from(block::timestamp()))?;
+        let unvested = checked_sub(total_amount, vested)?;
+
+        let mut grant = self.grants.setter(grant_id);
+        grant.total_amount.set(vested);
+        grant.revoked.set(true);
+
+        let locked = self.total_locked.get();
+        self.total_locked.set(checked_sub(locked, unvested)?);
+
+        if unvested > U256::from(0) {
+            let token = ISPPToken::new(self.token_contract.get());
+            if !token.transfer(Call::new_in(self), recipient, unvested)? {
+                return Err(TokenTransferFailed {}.encode());
+            }
+        }
+
+        evm::log(GrantRevoked {
+            grantId: grant_id,
+            recipient,
+            unvestedAmount: unvested,
+        });
+
+        Ok(unvested)
+    }
+
+    /// Get the amount vested so far (released or not)
+    pub fn vested_amount(&self, grant_id: U256) -> Result<U256, Vec<u8>> {
+        if self.grants.get(grant_id).beneficiary.get() == Address::ZERO {
+            return Err(GrantNotFound {}.encode());
+        }
+
+        self._vested_amount(grant_id, U256::from(block::timestamp()))
+    }
+
+    /// Get the amount that can be released right now
+    pub fn releasable_amount(&self, grant_id: U256) -> Result<U256, Vec<u8>> {
+        let grant = self.grants.get(grant_id);
+        if grant.beneficiary.get() == Address::ZERO {
+            return Err(GrantNotFound {}.encode());
+        }
+
+        checked_sub(
+            self._vested_amount(grant_id, U256::from(block::timestamp()))?,
+            grant.released.get(),
+        )
+    }
+
+    /// Get grant details
+    /// @return (beneficiary, totalAmount, released, start, cliff, duration, linear, revoked)
+    pub fn get_grant(
+        &self,
+        grant_id: U256,
+    ) -> Result<(Address, U256, U256, U256, U256, U256, bool, bool), Vec<u8>> {
+        let grant = self.grants.get(grant_id);
+        if grant.beneficiary.get() == Address::ZERO {
+            return Err(GrantNotFound {}.encode());
+        }
+
+        Ok((
+            grant.beneficiary.get(),
+            grant.total_amount.get(),
+            grant.released.get(),
+            grant.start.get(),
+            grant.cliff.get(),
+            grant.duration.get(),
+            grant.linear.get(),
+            grant.revoked.get(),
+        ))
+    }
+
+    /// Get all grant IDs of a beneficiary
+    pub fn get_beneficiary_grants(&self, beneficiary: Address) -> Result<Vec<U256>, Vec<u8>> {
+        let ids = self.beneficiary_grants.get(beneficiary);
+        let mut result = Vec::with_capacity(ids.len());
+        for i in 0..ids.len() {
+            result.push(ids.get(i).unwrap_or_default());
+        }
+        Ok(result)
+    }
+
+    /// Get total tokens still locked in grants
+    pub fn total_locked(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_locked.get())
+    }
+}
+
+// ==================== Internal Functions ====================
+
+impl RewardVesting {
+    /// Vested amount of a grant at a timestamp
+    fn _vested_amount(&self, grant_id: U256, timestamp: U256) -> Result<U256, Vec<u8>> {
+        let grant = self.grants.get(grant_id);
+        let total = grant.total_amount.get();
+
+        // A revoked grant keeps exactly what had vested at revocation
+        if grant.revoked.get() {
+            return Ok(total);
+        }
+
+        let start = grant.start.get();
+        if timestamp < checked_add(start, grant.cliff.get())? {
+            return Ok(U256::from(0));
+        }
+
+        if !grant.linear.get() {
+            return Ok(total);
+        }
+
+        let elapsed = checked_sub(timestamp, start)?;
+        let duration = grant.duration.get();
+        if elapsed >= duration {
+            Ok(total)
+        } else {
+            mul_div(total, elapsed, duration, Rounding::Down)
+        }
+    }
+}
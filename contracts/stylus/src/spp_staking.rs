@@ -0,0 +1,564 @@
+//! # SPP Staking Contract
+//!
+//! Holders stake SPP and earn a share of a reward stream.
+//!
+//! ## Reward Stream:
+//! - Rewards are streamed linearly over `rewards_duration` at `reward_rate`
+//!   tokens per second, shared pro-rata to stake (Synthetix-style
+//!   reward-per-token accumulator)
+//! - Reward tokens are sent to this contract first (e.g. by naming it as the
+//!   treasury in DeflatinaryBurn's split policy) and then announced with
+//!   `notify_reward_amount`
+//! - `reward_per_token` is scaled by `TOKEN_UNIT` (10^18) for precision
+//! - Rewards streamed while nothing is staked have no recipient; they are
+//!   set aside and the owner can recover them with `recover_unallocated`
+//!
+//! ## Unbonding:
+//! - `unstake` stops rewards immediately and starts an unbonding period
+//! - `withdraw` returns the tokens once the period has elapsed
+//! - Unstaking again adds to the pending amount and restarts the period
+
+use stylus_sdk::{
+    alloy_primitives::{Address, U256},
+    prelude::*,
+    msg,
+    block,
+    contract,
+    call::Call,
+};
+
+use crate::fixed_point::{checked_add, checked_mul, checked_sub, mul_div, Rounding, TOKEN_UNIT};
+
+/// Default reward stream length (7 days)
+const DEFAULT_REWARDS_DURATION: u64 = 604_800;
+
+/// Default unbonding period (7 days)
+const DEFAULT_UNBONDING_PERIOD: u64 = 604_800;
+
+sol_interface! {
+    interface ISPPToken {
+        function transfer(address to, uint256 amount) external returns (bool);
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+sol_storage! {
+    /// Main SPPStaking contract storage
+    #[entrypoint]
+    pub struct SPPStaking {
+        /// Contract owner (admin)
+        address owner;
+
+        /// SPP Token contract address
+        address token_contract;
+
+        /// Account allowed to announce new rewards (besides the owner)
+        address reward_distributor;
+
+        /// Staked balances
+        mapping(address => uint256) stakes;
+
+        /// Total staked (earning rewards)
+        uint256 total_staked;
+
+        /// Pending unbonding withdrawals
+        mapping(address => Unbonding) unbonding;
+
+        /// Total tokens waiting in unbonding
+        uint256 total_unbonding;
+
+        /// Unbonding period in seconds
+        uint256 unbonding_period;
+
+        /// Reward stream state
+        uint256 reward_rate; // Tokens per second
+        uint256 rewards_duration;
+        uint256 period_finish;
+        uint256 last_update_time;
+        uint256 reward_per_token_stored; // Scaled by TOKEN_UNIT
+
+        /// Per-account accumulator snapshot and accrued rewards
+        mapping(address => uint256) user_reward_per_token_paid;
+        mapping(address => uint256) rewards;
+
+        /// Reward tokens announced and not yet claimed
+        uint256 reward_balance;
+
+        /// Part of reward_balance streamed while nothing was staked, or
+        /// left over when the reward rate was rounded down
+        uint256 unallocated_rewards;
+    }
+
+    /// Tokens waiting to be withdrawn
+    pub struct Unbonding {
+        uint256 amount;
+        uint256 release_at;
+    }
+}
+
+sol! {
+    event Staked(address indexed account, uint256 amount);
+
+    event Unstaked(
+        address indexed account,
+        uint256 amount,
+        uint256 releaseAt
+    );
+
+    event Withdrawn(address indexed account, uint256 amount);
+
+    event RewardPaid(address indexed account, uint256 reward);
+
+    event RewardAdded(
+        uint256 reward,
+        uint256 rewardRate,
+        uint256 periodFinish
+    );
+
+    event RewardsDurationUpdated(uint256 duration);
+
+    event UnbondingPeriodUpdated(uint256 period);
+
+    event RewardDistributorUpdated(address indexed distributor);
+
+    event UnallocatedRewardsRecovered(address indexed to, uint256 amount);
+
+    error Unauthorized();
+    error InvalidAddress();
+    error InvalidAmount();
+    error InsufficientStake();
+    error NothingToWithdraw();
+    error StillUnbonding();
+    error NothingToClaim();
+    error RewardPeriodActive();
+    error RewardNotFunded();
+    error InvalidDuration();
+    error TokenTransferFailed();
+    error NothingToRecover();
+}
+
+#[public]
+impl SPPStaking {
+    /// Initialize with the staked (and reward) token
+    pub fn init(&mut self, token_contract: Address) -> Result<(), Vec<u8>> {
+        if token_contract == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.owner.set(msg::sender());
+        self.token_contract.set(token_contract);
+        self.rewards_duration.set(U256::from(DEFAULT_REWARDS_DURATION));
+        self.unbonding_period.set(U256::from(DEFAULT_UNBONDING_PERIOD));
+        Ok(())
+    }
+
+    // ==================== Staking ====================
+
+    /// Stake tokens (requires an SPP allowance or permit for this contract)
+    pub fn stake(&mut self, amount: U256) -> Result<(), Vec<u8>> {
+        let account = msg::sender();
+        if amount == U256::from(0) {
+            return Err(InvalidAmount {}.encode());
+        }
+
+        self._update_reward(account)?;
+
+        let staked = self.stakes.get(account);
+        self.stakes.setter(account).set(checked_add(staked, amount)?);
+        let total = self.total_staked.get();
+        self.total_staked.set(checked_add(total, amount)?);
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer_from(Call::new_in(self), account, contract::address(), amount)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(Staked { account, amount });
+
+        Ok(())
+    }
+
+    /// Stop staking tokens and start their unbonding period
+    /// @return Timestamp at which the unbonding tokens can be withdrawn
+    pub fn unstake(&mut self, amount: U256) -> Result<U256, Vec<u8>> {
+        let account = msg::sender();
+        if amount == U256::from(0) {
+            return Err(InvalidAmount {}.encode());
+        }
+
+        let staked = self.stakes.get(account);
+        if staked < amount {
+            return Err(InsufficientStake {}.encode());
+        }
+
+        self._update_reward(account)?;
+
+        self.stakes.setter(account).set(checked_sub(staked, amount)?);
+        let total = self.total_staked.get();
+        self.total_staked.set(checked_sub(total, amount)?);
+
+        let release_at = checked_add(U256::from(block::timestamp()), self.unbonding_period.get())?;
+        let pending = self.unbonding.get(account).amount.get();
+        let mut entry = self.unbonding.setter(account);
+        entry.amount.set(checked_add(pending, amount)?);
+        entry.release_at.set(release_at);
+
+        let total_unbonding = self.total_unbonding.get();
+        self.total_unbonding.set(checked_add(total_unbonding, amount)?);
+
+        evm::log(Unstaked {
+            account,
+            amount,
+            releaseAt: release_at,
+        });
+
+        Ok(release_at)
+    }
+
+    /// Withdraw tokens whose unbonding period has elapsed
+    /// @return Amount withdrawn
+    pub fn withdraw(&mut self) -> Result<U256, Vec<u8>> {
+        let account = msg::sender();
+
+        let (amount, release_at) = {
+            let entry = self.unbonding.get(account);
+            (entry.amount.get(), entry.release_at.get())
+        };
+
+        if amount == U256::from(0) {
+            return Err(NothingToWithdraw {}.encode());
+        }
+
+        if U256::from(block::timestamp()) < release_at {
+            return Err(StillUnbonding {}.encode());
+        }
+
+        let mut entry = self.unbonding.setter(account);
+        entry.amount.set(U256::from(0));
+        entry.release_at.set(U256::from(0));
+
+        let total_unbonding = self.total_unbonding.get();
+        self.total_unbonding.set(checked_sub(total_unbonding, amount)?);
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer(Call::new_in(self), account, amount)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(Withdrawn { account, amount });
+
+        Ok(amount)
+    }
+
+    /// Claim accrued rewards
+    /// @return Amount claimed
+    pub fn claim(&mut self) -> Result<U256, Vec<u8>> {
+        let account = msg::sender();
+        self._update_reward(account)?;
+
+        let reward = self.rewards.get(account);
+        if reward == U256::from(0) {
+            return Err(NothingToClaim {}.encode());
+        }
+
+        self.rewards.setter(account).set(U256::from(0));
+        let reward_balance = self.reward_balance.get();
+        self.reward_balance.set(checked_sub(reward_balance, reward)?);
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer(Call::new_in(self), account, reward)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(RewardPaid { account, reward });
+
+        Ok(reward)
+    }
+
+    // ==================== Reward Stream ====================
+
+    /// Announce `reward` tokens already sent to this contract and stream them
+    /// (plus any undistributed leftover) over the next `rewards_duration`
+    /// (owner or reward distributor)
+    pub fn notify_reward_amount(&mut self, reward: U256) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if caller != self.owner.get() && caller != self.reward_distributor.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if reward == U256::from(0) {
+            return Err(InvalidAmount {}.encode());
+        }
+
+        self._update_reward(Address::ZERO)?;
+
+        // Tokens must be here and not already backing stakes or earlier rewards
+        let token = ISPPToken::new(self.token_contract.get());
+        let balance = token.balance_of(Call::new(), contract::address())?;
+        let committed = checked_add(
+            checked_add(self.total_staked.get(), self.total_unbonding.get())?,
+            self.reward_balance.get(),
+        )?;
+        if balance < checked_add(committed, reward)? {
+            return Err(RewardNotFunded {}.encode());
+        }
+
+        let now = U256::from(block::timestamp());
+        let duration = self.rewards_duration.get();
+        let period_finish = self.period_finish.get();
+
+        let total_reward = if now < period_finish {
+            let leftover = checked_mul(checked_sub(period_finish, now)?, self.reward_rate.get())?;
+            checked_add(reward, leftover)?
+        } else {
+            reward
+        };
+
+        let reward_rate = total_reward / duration;
+        let new_period_finish = checked_add(now, duration)?;
+
+        // The rate rounds down; the dust is never streamed, so set it aside
+        let dust = checked_sub(total_reward, checked_mul(reward_rate, duration)?)?;
+        if dust > U256::from(0) {
+            let unallocated = self.unallocated_rewards.get();
+            self.unallocated_rewards.set(checked_add(unallocated, dust)?);
+        }
+
+        self.reward_rate.set(reward_rate);
+        self.last_update_time.set(now);
+        self.period_finish.set(new_period_finish);
+
+        let reward_balance = self.reward_balance.get();
+        self.reward_balance.set(checked_add(reward_balance, reward)?);
+
+        evm::log(RewardAdded {
+            reward,
+            rewardRate: reward_rate,
+            periodFinish: new_period_finish,
+        });
+
+        Ok(())
+    }
+
+    /// Send rewards streamed while nothing was staked, plus reward-rate
+    /// rounding dust, to `to` (admin only)
+    /// @return Amount recovered
+    pub fn recover_unallocated(&mut self, to: Address) -> Result<U256, Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if to == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self._update_reward(Address::ZERO)?;
+
+        let amount = self.unallocated_rewards.get();
+        if amount == U256::from(0) {
+            return Err(NothingToRecover {}.encode());
+        }
+
+        self.unallocated_rewards.set(U256::from(0));
+        let reward_balance = self.reward_balance.get();
+        self.reward_balance.set(checked_sub(reward_balance, amount)?);
+
+        let token = ISPPToken::new(self.token_contract.get());
+        if !token.transfer(Call::new_in(self), to, amount)? {
+            return Err(TokenTransferFailed {}.encode());
+        }
+
+        evm::log(UnallocatedRewardsRecovered { to, amount });
+
+        Ok(amount)
+    }
+
+    /// Set the account allowed to announce rewards (admin only)
+    pub fn set_reward_distributor(&mut self, distributor: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.reward_distributor.set(distributor);
+
+        evm::log(RewardDistributorUpdated { distributor });
+
+        Ok(())
+    }
+
+    /// Set the length of future reward streams (admin only, between streams)
+    pub fn set_rewards_duration(&mut self, duration: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if duration == U256::from(0) {
+            return Err(InvalidDuration {}.encode());
+        }
+
+        if U256::from(block::timestamp()) < self.period_finish.get() {
+            return Err(RewardPeriodActive {}.encode());
+        }
+
+        self.rewards_duration.set(duration);
+
+        evm::log(RewardsDurationUpdated { duration });
+
+        Ok(())
+    }
+
+    /// Set the unbonding period for future unstakes (admin only)
+    pub fn set_unbonding_period(&mut self, period: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.unbonding_period.set(period);
+
+        evm::log(UnbondingPeriodUpdated { period });
+
+        Ok(())
+    }
+
+    // ==================== View Functions ====================
+
+    /// Get rewards accrued by an account and not yet claimed
+    pub fn pending_rewards(&self, account: Address) -> Result<U256, Vec<u8>> {
+        self._earned(account, self._reward_per_token()?)
+    }
+
+    /// Get the current reward per staked token (scaled by 10^18)
+    pub fn reward_per_token(&self) -> Result<U256, Vec<u8>> {
+        self._reward_per_token()
+    }
+
+    /// Get the staked balance of an account
+    pub fn staked_balance(&self, account: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.stakes.get(account))
+    }
+
+    /// Get the total staked
+    pub fn total_staked(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_staked.get())
+    }
+
+    /// Get an account's pending unbonding withdrawal
+    /// @return (amount, releaseAt)
+    pub fn get_unbonding(&self, account: Address) -> Result<(U256, U256), Vec<u8>> {
+        let entry = self.unbonding.get(account);
+        Ok((entry.amount.get(), entry.release_at.get()))
+    }
+
+    /// Get the unbonding period in seconds
+    pub fn unbonding_period(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.unbonding_period.get())
+    }
+
+    /// Get rewards streamed while nothing was staked (recoverable by the owner)
+    pub fn unallocated_rewards(&self) -> Result<U256, Vec<u8>> {
+        let mut unallocated = self.unallocated_rewards.get();
+        if self.total_staked.get() == U256::from(0) {
+            unallocated = checked_add(unallocated, self._pending_emission()?)?;
+        }
+        Ok(unallocated)
+    }
+
+    /// Get the reward stream state
+    /// @return (rewardRate, periodFinish, rewardsDuration, rewardBalance)
+    pub fn get_reward_stream(&self) -> Result<(U256, U256, U256, U256), Vec<u8>> {
+        Ok((
+            self.reward_rate.get(),
+            self.period_finish.get(),
+            self.rewards_duration.get(),
+            self.reward_balance.get(),
+        ))
+    }
+}
+
+// ==================== Internal Functions ====================
+
+impl SPPStaking {
+    /// Checkpoint the global accumulator and an account's accrued rewards
+    /// (pass `Address::ZERO` to only update the global state)
+    fn _update_reward(&mut self, account: Address) -> Result<(), Vec<u8>> {
+        // Nobody earns what is streamed to an empty pool; set it aside
+        if self.total_staked.get() == U256::from(0) {
+            let idle = self._pending_emission()?;
+            if idle > U256::from(0) {
+                let unallocated = self.unallocated_rewards.get();
+                self.unallocated_rewards.set(checked_add(unallocated, idle)?);
+            }
+        }
+
+        let reward_per_token = self._reward_per_token()?;
+        self.reward_per_token_stored.set(reward_per_token);
+        self.last_update_time.set(self._last_time_reward_applicable());
+
+        if account != Address::ZERO {
+            let earned = self._earned(account, reward_per_token)?;
+            self.rewards.setter(account).set(earned);
+            self.user_reward_per_token_paid.setter(account).set(reward_per_token);
+        }
+
+        Ok(())
+    }
+
+    /// min(now, period_finish)
+    fn _last_time_reward_applicable(&self) -> U256 {
+        U256::from(block::timestamp()).min(self.period_finish.get())
+    }
+
+    /// Rewards streamed since the last checkpoint
+    fn _pending_emission(&self) -> Result<U256, Vec<u8>> {
+        let elapsed = self
+            ._last_time_reward_applicable()
+            .saturating_sub(self.last_update_time.get());
+        checked_mul(elapsed, self.reward_rate.get())
+    }
+
+    /// Accumulated rewards per staked token, scaled by TOKEN_UNIT
+    fn _reward_per_token(&self) -> Result<U256, Vec<u8>> {
+        let stored = self.reward_per_token_stored.get();
+        let total_staked = self.total_staked.get();
+        if total_staked == U256::from(0) {
+            return Ok(stored);
+        }
+
+        accrue_reward_per_token(stored, self._pending_emission()?, total_staked)
+    }
+
+    /// Rewards of an account given the current accumulator
+    fn _earned(&self, account: Address, reward_per_token: U256) -> Result<U256, Vec<u8>> {
+        earned_rewards(
+            self.stakes.get(account),
+            reward_per_token,
+            self.user_reward_per_token_paid.get(account),
+            self.rewards.get(account),
+        )
+    }
+}
+
+// ==================== Reward Math Helpers ====================
+
+/// Advance the reward-per-token accumulator (scaled by 1e18) by rewards
+/// emitted over a non-empty stake
+pub fn accrue_reward_per_token(
+    stored: U256,
+    emitted: U256,
+    total_staked: U256,
+) -> Result<U256, Vec<u8>> {
+    checked_add(stored, mul_div(emitted, TOKEN_UNIT, total_staked, Rounding::Down)?)
+}
+
+/// Rewards of a stake: what was already owed plus the accumulator growth
+/// since it was last settled
+pub fn earned_rewards(
+    stake: U256,
+    reward_per_token: U256,
+    reward_per_token_paid: U256,
+    owed: U256,
+) -> Result<U256, Vec<u8>> {
+    let delta = checked_sub(reward_per_token, reward_per_token_paid)?;
+    checked_add(owed, mul_div(stake, delta, TOKEN_UNIT, Rounding::Down)?)
+}
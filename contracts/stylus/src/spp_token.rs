@@ -7,15 +7,47 @@
 //! ## Features:
 //! - Standard ERC-20 interface (transfer, approve, transferFrom)
 //! - Burn mechanism for deflationary tokenomics
-//! - Mint capability for initial distribution and rewards
+//! - Capped minting: a hard `max_supply` that can only be lowered, and a
+//!   MINTER role with per-minter allowances and per-window rate limits
 //! - Integration with burn contract for automated burns
+//! - EIP-2612 `permit`: approvals signed off-chain and relayed by anyone
+//! - ERC20Votes-style checkpoints (ERC-5805 / ERC-6372, block-number clock):
+//!   historical votes and total supply for governance and snapshots
+//! - Checked arithmetic: balance and supply updates revert with
+//!   `Overflow` / `Underflow` instead of wrapping
+//!
+//! ## Supply Accounting:
+//! - `total_supply` already excludes burned tokens (burns reduce it)
+//! - `circulating_supply = total_supply - balance(treasury) - balances of
+//!   non-circulating accounts` (vesting, staking, reward pools, ...)
 
 use stylus_sdk::{
-    alloy_primitives::{Address, U256},
+    alloy_primitives::{Address, FixedBytes, U256},
     prelude::*,
     msg,
+    block,
+    storage::{StorageU256, StorageVec},
 };
 
+use crate::eip712;
+use crate::fixed_point::{checked_add, checked_sub};
+
+/// Default minter rate-limit window (1 day)
+const DEFAULT_MINT_WINDOW: u64 = 86_400;
+
+/// EIP-5805 vote delegation struct type
+const DELEGATION_TYPE: &[u8] = b"Delegation(address delegatee,uint256 nonce,uint256 expiry)";
+
+/// ERC-6372 clock description
+const CLOCK_MODE: &str = "mode=blocknumber&from=default";
+
+/// EIP-712 domain version
+const DOMAIN_VERSION: &str = "1";
+
+/// EIP-2612 permit struct type
+const PERMIT_TYPE: &[u8] =
+    b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)";
+
 sol_storage! {
     /// Main SPPToken contract storage
     #[entrypoint]
@@ -46,6 +78,47 @@ sol_storage! {
 
         /// Total burned (for tracking)
         uint256 total_burned;
+
+        /// Treasury address (excluded from circulating supply)
+        address treasury;
+
+        /// Accounts whose balances are locked (excluded from circulating supply)
+        address[] non_circulating_accounts;
+
+        /// Whether an account is in `non_circulating_accounts`
+        mapping(address => bool) is_non_circulating;
+
+        /// EIP-2612 permit nonces
+        mapping(address => uint256) nonces;
+
+        /// Hard supply cap (can only be lowered)
+        uint256 max_supply;
+
+        /// Minter roles
+        mapping(address => Minter) minters;
+
+        /// Rate-limit window length in seconds
+        uint256 mint_window;
+
+        /// Vote delegation (account => delegatee)
+        mapping(address => address) delegates;
+
+        /// Per-delegatee vote checkpoints: block numbers and votes (parallel arrays)
+        mapping(address => uint256[]) vote_checkpoint_blocks;
+        mapping(address => uint256[]) vote_checkpoint_votes;
+
+        /// Total supply checkpoints: block numbers and supply (parallel arrays)
+        uint256[] supply_checkpoint_blocks;
+        uint256[] supply_checkpoint_values;
+    }
+
+    /// MINTER role grant
+    pub struct Minter {
+        bool active;
+        uint256 allowance; // Remaining lifetime allowance
+        uint256 rate_limit; // Max minted per window (0 = no rate limit)
+        uint256 window_start;
+        uint256 window_minted;
     }
 }
 
@@ -72,18 +145,65 @@ sol! {
         uint256 value
     );
 
+    event TreasuryUpdated(address indexed treasury);
+
+    event NonCirculatingAccountUpdated(
+        address indexed account,
+        bool excluded
+    );
+
+    event MaxSupplyLowered(uint256 oldMaxSupply, uint256 newMaxSupply);
+
+    event MinterGranted(
+        address indexed minter,
+        uint256 allowance,
+        uint256 rateLimit
+    );
+
+    event MinterRevoked(address indexed minter);
+
+    event MintWindowUpdated(uint256 window);
+
+    event DelegateChanged(
+        address indexed delegator,
+        address indexed fromDelegate,
+        address indexed toDelegate
+    );
+
+    event DelegateVotesChanged(
+        address indexed delegate,
+        uint256 previousVotes,
+        uint256 newVotes
+    );
+
     error InsufficientBalance();
     error InsufficientAllowance();
     error Unauthorized();
     error InvalidAddress();
+    error PermitExpired();
+    error InvalidSigner();
+    error NotMinter();
+    error InvalidMaxSupply();
+    error MaxSupplyExceeded();
+    error MintAllowanceExceeded();
+    error MintRateLimitExceeded();
+    error InvalidMintWindow();
+    error DelegationExpired();
+    error InvalidNonce();
+    error FutureLookup();
+    error CheckpointNotFound();
 }
 
 #[public]
 impl SPPToken {
-    /// Initialize the token with name, symbol, and initial supply
-    pub fn init(&mut self, initial_supply: U256) -> Result<(), Vec<u8>> {
+    /// Initialize the token with name, symbol, initial supply and hard cap
+    pub fn init(&mut self, initial_supply: U256, max_supply: U256) -> Result<(), Vec<u8>> {
         let caller = msg::sender();
 
+        if initial_supply > max_supply {
+            return Err(InvalidMaxSupply {}.encode());
+        }
+
         // Set token metadata
         self.name.set_str("Sports Performance Protocol Token");
         self.symbol.set_str("SPP");
@@ -92,6 +212,10 @@ impl SPPToken {
         // Set owner
         self.owner.set(caller);
 
+        // Supply cap and minter rate-limit window
+        self.max_supply.set(max_supply);
+        self.mint_window.set(U256::from(DEFAULT_MINT_WINDOW));
+
         // Mint initial supply to owner
         self.total_supply.set(initial_supply);
         self.balances.setter(caller).set(initial_supply);
@@ -99,6 +223,9 @@ impl SPPToken {
         // Initialize total burned
         self.total_burned.set(U256::from(0));
 
+        // Nobody has delegated yet, so only the supply is checkpointed
+        self._update_votes(Address::ZERO, caller, initial_supply)?;
+
         // Emit mint event
         evm::log(Mint {
             to: caller,
@@ -125,6 +252,66 @@ impl SPPToken {
         Ok(())
     }
 
+    /// Set the treasury address excluded from circulating supply (admin only)
+    pub fn set_treasury(&mut self, treasury: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        self.treasury.set(treasury);
+
+        evm::log(TreasuryUpdated { treasury });
+
+        Ok(())
+    }
+
+    /// Exclude or include an account's balance in circulating supply (admin only)
+    /// Use for locked balances such as vesting, staking or reward pool contracts.
+    pub fn set_non_circulating(&mut self, account: Address, excluded: bool) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if account == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        if excluded == self.is_non_circulating.get(account) {
+            return Ok(());
+        }
+
+        if excluded {
+            self.non_circulating_accounts.push(account);
+        } else {
+            // Swap-remove from the list
+            let len = self.non_circulating_accounts.len();
+            for i in 0..len {
+                if self.non_circulating_accounts.get(i) == Some(account) {
+                    let last = self.non_circulating_accounts.get(len - 1).unwrap_or_default();
+                    if let Some(mut slot) = self.non_circulating_accounts.setter(i) {
+                        slot.set(last);
+                    }
+                    self.non_circulating_accounts.pop();
+                    break;
+                }
+            }
+        }
+        self.is_non_circulating.setter(account).set(excluded);
+
+        evm::log(NonCirculatingAccountUpdated { account, excluded });
+
+        Ok(())
+    }
+
+    /// Get accounts excluded from circulating supply (besides the treasury)
+    pub fn get_non_circulating_accounts(&self) -> Result<Vec<Address>, Vec<u8>> {
+        let mut accounts = Vec::with_capacity(self.non_circulating_accounts.len());
+        for i in 0..self.non_circulating_accounts.len() {
+            accounts.push(self.non_circulating_accounts.get(i).unwrap_or_default());
+        }
+        Ok(accounts)
+    }
+
     // ==================== ERC-20 Standard Functions ====================
 
     /// Get token name
@@ -167,19 +354,7 @@ impl SPPToken {
     /// Approve spender to spend tokens
     pub fn approve(&mut self, spender: Address, amount: U256) -> Result<bool, Vec<u8>> {
         let owner = msg::sender();
-
-        if spender == Address::ZERO {
-            return Err(InvalidAddress {}.encode());
-        }
-
-        self.allowances.setter(owner).setter(spender).set(amount);
-
-        evm::log(Approval {
-            owner,
-            spender,
-            value: amount,
-        });
-
+        self._approve(owner, spender, amount)?;
         Ok(true)
     }
 
@@ -202,7 +377,7 @@ impl SPPToken {
         self.allowances
             .setter(from)
             .setter(spender)
-            .set(current_allowance - amount);
+            .set(checked_sub(current_allowance, amount)?);
 
         // Execute transfer
         self._transfer(from, to, amount)?;
@@ -210,6 +385,169 @@ impl SPPToken {
         Ok(true)
     }
 
+    // ==================== EIP-2612 Permit ====================
+
+    /// Approve `spender` with an owner's off-chain signature (callable by anyone)
+    /// @param owner Token owner who signed the permit
+    /// @param spender Approved spender
+    /// @param value Allowance to set
+    /// @param deadline Last timestamp at which the permit is valid
+    /// @param v Signature recovery id (27 or 28)
+    /// @param r Signature r
+    /// @param s Signature s
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        v: u8,
+        r: FixedBytes<32>,
+        s: FixedBytes<32>,
+    ) -> Result<(), Vec<u8>> {
+        if U256::from(block::timestamp()) > deadline {
+            return Err(PermitExpired {}.encode());
+        }
+
+        let nonce = self.nonces.get(owner);
+        let struct_hash = eip712::hash_words(&[
+            eip712::hash_bytes(PERMIT_TYPE).0,
+            eip712::address_word(owner),
+            eip712::address_word(spender),
+            eip712::uint_word(value),
+            eip712::uint_word(nonce),
+            eip712::uint_word(deadline),
+        ]);
+        let digest = eip712::typed_data_hash(self._domain_separator(), struct_hash);
+
+        let signer = eip712::recover_signer(digest, v, r, s)?;
+        if signer != owner {
+            return Err(InvalidSigner {}.encode());
+        }
+
+        self.nonces.setter(owner).set(checked_add(nonce, U256::from(1))?);
+        self._approve(owner, spender, value)
+    }
+
+    /// Get the current permit nonce of an owner
+    pub fn nonces(&self, owner: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.nonces.get(owner))
+    }
+
+    /// Get the EIP-712 domain separator used for permits
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    pub fn domain_separator(&self) -> Result<FixedBytes<32>, Vec<u8>> {
+        Ok(self._domain_separator())
+    }
+
+    // ==================== Votes & Delegation ====================
+
+    /// ERC-6372 clock: the current block number
+    pub fn clock(&self) -> Result<u64, Vec<u8>> {
+        Ok(block::number())
+    }
+
+    /// ERC-6372 clock mode
+    #[selector(name = "CLOCK_MODE")]
+    pub fn clock_mode(&self) -> Result<String, Vec<u8>> {
+        Ok(CLOCK_MODE.to_string())
+    }
+
+    /// Get the delegatee of an account (zero if it never delegated)
+    pub fn delegates(&self, account: Address) -> Result<Address, Vec<u8>> {
+        Ok(self.delegates.get(account))
+    }
+
+    /// Delegate the caller's votes (delegate to self to activate own votes)
+    pub fn delegate(&mut self, delegatee: Address) -> Result<(), Vec<u8>> {
+        let account = msg::sender();
+        self._delegate(account, delegatee)
+    }
+
+    /// Delegate votes with an off-chain EIP-712 signature (callable by anyone)
+    /// @param delegatee Address receiving the votes
+    /// @param nonce Signer's current nonce (shared with permit)
+    /// @param expiry Last timestamp at which the signature is valid
+    pub fn delegate_by_sig(
+        &mut self,
+        delegatee: Address,
+        nonce: U256,
+        expiry: U256,
+        v: u8,
+        r: FixedBytes<32>,
+        s: FixedBytes<32>,
+    ) -> Result<(), Vec<u8>> {
+        if U256::from(block::timestamp()) > expiry {
+            return Err(DelegationExpired {}.encode());
+        }
+
+        let struct_hash = eip712::hash_words(&[
+            eip712::hash_bytes(DELEGATION_TYPE).0,
+            eip712::address_word(delegatee),
+            eip712::uint_word(nonce),
+            eip712::uint_word(expiry),
+        ]);
+        let digest = eip712::typed_data_hash(self._domain_separator(), struct_hash);
+        let signer = eip712::recover_signer(digest, v, r, s)?;
+
+        let current_nonce = self.nonces.get(signer);
+        if nonce != current_nonce {
+            return Err(InvalidNonce {}.encode());
+        }
+        self.nonces.setter(signer).set(checked_add(current_nonce, U256::from(1))?);
+
+        self._delegate(signer, delegatee)
+    }
+
+    /// Get the current votes of an account
+    pub fn get_votes(&self, account: Address) -> Result<U256, Vec<u8>> {
+        Ok(latest_checkpoint(&self.vote_checkpoint_votes.get(account)))
+    }
+
+    /// Get the votes of an account at the end of a past block
+    /// @param timepoint Block number, must be in the past
+    pub fn get_past_votes(&self, account: Address, timepoint: U256) -> Result<U256, Vec<u8>> {
+        self._check_past(timepoint)?;
+        Ok(upper_lookup(
+            &self.vote_checkpoint_blocks.get(account),
+            &self.vote_checkpoint_votes.get(account),
+            timepoint,
+        ))
+    }
+
+    /// Get the total supply at the end of a past block
+    /// @param timepoint Block number, must be in the past
+    pub fn get_past_total_supply(&self, timepoint: U256) -> Result<U256, Vec<u8>> {
+        self._check_past(timepoint)?;
+        Ok(upper_lookup(
+            &self.supply_checkpoint_blocks,
+            &self.supply_checkpoint_values,
+            timepoint,
+        ))
+    }
+
+    /// Get the number of vote checkpoints of an account
+    pub fn num_checkpoints(&self, account: Address) -> Result<U256, Vec<u8>> {
+        Ok(U256::from(self.vote_checkpoint_blocks.get(account).len()))
+    }
+
+    /// Get a vote checkpoint of an account
+    /// @return (fromBlock, votes)
+    pub fn checkpoints(&self, account: Address, pos: U256) -> Result<(U256, U256), Vec<u8>> {
+        let blocks = self.vote_checkpoint_blocks.get(account);
+        if pos >= U256::from(blocks.len()) {
+            return Err(CheckpointNotFound {}.encode());
+        }
+
+        let index = pos.to::<usize>();
+        let votes = self.vote_checkpoint_votes.get(account);
+        Ok((
+            blocks.get(index).unwrap_or_default(),
+            votes.get(index).unwrap_or_default(),
+        ))
+    }
+
     // ==================== Burn & Mint Functions ====================
 
     /// Burn tokens from caller's balance
@@ -219,36 +557,53 @@ impl SPPToken {
         Ok(())
     }
 
-    /// Burn tokens from a specific address (only burn contract)
+    /// Burn tokens from a specific address
+    /// The burn contract and owner burn directly; any other caller spends the
+    /// allowance `from` granted it (e.g. AthleteNFT upgrade purchases).
     pub fn burn_from(&mut self, from: Address, amount: U256) -> Result<(), Vec<u8>> {
         let caller = msg::sender();
 
-        // Only burn contract can call this
         if caller != self.burn_contract.get() && caller != self.owner.get() {
-            return Err(Unauthorized {}.encode());
+            let current_allowance = self.allowances.get(from).get(caller);
+            if current_allowance < amount {
+                return Err(InsufficientAllowance {}.encode());
+            }
+
+            self.allowances
+                .setter(from)
+                .setter(caller)
+                .set(checked_sub(current_allowance, amount)?);
         }
 
         self._burn(from, amount)?;
         Ok(())
     }
 
-    /// Mint new tokens (only owner)
+    /// Mint new tokens (minters only)
+    /// Bounded by the hard cap, the minter's allowance and its rate limit.
     pub fn mint(&mut self, to: Address, amount: U256) -> Result<(), Vec<u8>> {
-        if msg::sender() != self.owner.get() {
-            return Err(Unauthorized {}.encode());
-        }
+        let minter = msg::sender();
 
         if to == Address::ZERO {
             return Err(InvalidAddress {}.encode());
         }
 
-        // Increase total supply
+        // Increase total supply, enforcing the hard cap
         let current_supply = self.total_supply.get();
-        self.total_supply.set(current_supply + amount);
+        let new_supply = checked_add(current_supply, amount)?;
+        if new_supply > self.max_supply.get() {
+            return Err(MaxSupplyExceeded {}.encode());
+        }
+
+        self._consume_mint_allowance(minter, amount)?;
+
+        self.total_supply.set(new_supply);
 
         // Increase recipient balance
         let recipient_balance = self.balances.get(to);
-        self.balances.setter(to).set(recipient_balance + amount);
+        self.balances.setter(to).set(checked_add(recipient_balance, amount)?);
+
+        self._update_votes(Address::ZERO, to, amount)?;
 
         evm::log(Mint { to, value: amount });
 
@@ -261,19 +616,316 @@ impl SPPToken {
         Ok(())
     }
 
+    // ==================== Supply Cap & Minters ====================
+
+    /// Lower the hard supply cap (admin only, never below total supply)
+    pub fn set_max_supply(&mut self, new_max_supply: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        let old_max_supply = self.max_supply.get();
+        if new_max_supply > old_max_supply || new_max_supply < self.total_supply.get() {
+            return Err(InvalidMaxSupply {}.encode());
+        }
+
+        self.max_supply.set(new_max_supply);
+
+        evm::log(MaxSupplyLowered {
+            oldMaxSupply: old_max_supply,
+            newMaxSupply: new_max_supply,
+        });
+
+        Ok(())
+    }
+
+    /// Grant or update the MINTER role (admin only)
+    /// @param minter Minter address (e.g. DeflatinaryBurn)
+    /// @param allowance Remaining lifetime mint allowance
+    /// @param rateLimit Max mint per window (0 = no rate limit)
+    pub fn grant_minter(
+        &mut self,
+        minter: Address,
+        allowance: U256,
+        rate_limit: U256,
+    ) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if minter == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        let mut grant = self.minters.setter(minter);
+        grant.active.set(true);
+        grant.allowance.set(allowance);
+        grant.rate_limit.set(rate_limit);
+
+        evm::log(MinterGranted {
+            minter,
+            allowance,
+            rateLimit: rate_limit,
+        });
+
+        Ok(())
+    }
+
+    /// Revoke the MINTER role (admin only)
+    pub fn revoke_minter(&mut self, minter: Address) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if !self.minters.get(minter).active.get() {
+            return Err(NotMinter {}.encode());
+        }
+
+        let mut grant = self.minters.setter(minter);
+        grant.active.set(false);
+        grant.allowance.set(U256::from(0));
+
+        evm::log(MinterRevoked { minter });
+
+        Ok(())
+    }
+
+    /// Set the minter rate-limit window in seconds (admin only)
+    pub fn set_mint_window(&mut self, window: U256) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.owner.get() {
+            return Err(Unauthorized {}.encode());
+        }
+
+        if window == U256::from(0) {
+            return Err(InvalidMintWindow {}.encode());
+        }
+
+        self.mint_window.set(window);
+
+        evm::log(MintWindowUpdated { window });
+
+        Ok(())
+    }
+
+    /// Get the hard supply cap
+    pub fn max_supply(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.max_supply.get())
+    }
+
+    /// Get the minter rate-limit window in seconds
+    pub fn mint_window(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.mint_window.get())
+    }
+
+    /// Check whether an address holds the MINTER role
+    pub fn is_minter(&self, account: Address) -> Result<bool, Vec<u8>> {
+        Ok(self.minters.get(account).active.get())
+    }
+
+    /// Get a minter grant
+    /// @return (active, allowance, rateLimit, windowStart, windowMinted)
+    pub fn get_minter(&self, minter: Address) -> Result<(bool, U256, U256, U256, U256), Vec<u8>> {
+        let grant = self.minters.get(minter);
+        Ok((
+            grant.active.get(),
+            grant.allowance.get(),
+            grant.rate_limit.get(),
+            grant.window_start.get(),
+            grant.window_minted.get(),
+        ))
+    }
+
+    /// Get how much a minter can mint right now (allowance, rate limit and cap)
+    pub fn mintable_amount(&self, minter: Address) -> Result<U256, Vec<u8>> {
+        let grant = self.minters.get(minter);
+        if !grant.active.get() {
+            return Ok(U256::from(0));
+        }
+
+        let mut mintable = grant.allowance.get();
+
+        let rate_limit = grant.rate_limit.get();
+        if rate_limit > U256::from(0) {
+            let window_minted = if self._window_expired(grant.window_start.get())? {
+                U256::from(0)
+            } else {
+                grant.window_minted.get()
+            };
+            mintable = mintable.min(rate_limit.saturating_sub(window_minted));
+        }
+
+        let headroom = self.max_supply.get().saturating_sub(self.total_supply.get());
+        Ok(mintable.min(headroom))
+    }
+
     /// Get total tokens burned
     pub fn get_total_burned(&self) -> Result<U256, Vec<u8>> {
         Ok(self.total_burned.get())
     }
 
-    /// Get circulating supply (total_supply - total_burned)
+    /// Get circulating supply
+    /// total_supply (already net of burns) minus the treasury balance and
+    /// the balances of non-circulating accounts
     pub fn circulating_supply(&self) -> Result<U256, Vec<u8>> {
-        let total = self.total_supply.get();
-        let burned = self.total_burned.get();
-        Ok(total - burned)
+        let mut excluded = Vec::new();
+
+        // The treasury counts once, even if it is also listed
+        let treasury = self.treasury.get();
+        if treasury != Address::ZERO && !self.is_non_circulating.get(treasury) {
+            excluded.push(self.balances.get(treasury));
+        }
+
+        for i in 0..self.non_circulating_accounts.len() {
+            let account = self.non_circulating_accounts.get(i).unwrap_or_default();
+            excluded.push(self.balances.get(account));
+        }
+
+        circulating_amount(self.total_supply.get(), &excluded)
     }
+}
+
+// ==================== Internal Functions ====================
 
-    // ==================== Internal Functions ====================
+impl SPPToken {
+    /// Change an account's delegatee and move its voting power
+    fn _delegate(&mut self, account: Address, delegatee: Address) -> Result<(), Vec<u8>> {
+        let old_delegate = self.delegates.get(account);
+        self.delegates.setter(account).set(delegatee);
+
+        evm::log(DelegateChanged {
+            delegator: account,
+            fromDelegate: old_delegate,
+            toDelegate: delegatee,
+        });
+
+        let balance = self.balances.get(account);
+        self._move_delegate_votes(old_delegate, delegatee, balance)
+    }
+
+    /// Checkpoint supply on mint/burn and move votes between delegatees
+    fn _update_votes(&mut self, from: Address, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        if from == Address::ZERO || to == Address::ZERO {
+            push_checkpoint(
+                &mut self.supply_checkpoint_blocks,
+                &mut self.supply_checkpoint_values,
+                self.total_supply.get(),
+            );
+        }
+
+        let from_delegate = self.delegates.get(from);
+        let to_delegate = self.delegates.get(to);
+        self._move_delegate_votes(from_delegate, to_delegate, amount)
+    }
+
+    /// Move `amount` votes from one delegatee to another
+    fn _move_delegate_votes(
+        &mut self,
+        src: Address,
+        dst: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        if src == dst || amount == U256::from(0) {
+            return Ok(());
+        }
+
+        if src != Address::ZERO {
+            let previous = latest_checkpoint(&self.vote_checkpoint_votes.get(src));
+            let new_votes = checked_sub(previous, amount)?;
+            self._write_vote_checkpoint(src, previous, new_votes);
+        }
+
+        if dst != Address::ZERO {
+            let previous = latest_checkpoint(&self.vote_checkpoint_votes.get(dst));
+            let new_votes = checked_add(previous, amount)?;
+            self._write_vote_checkpoint(dst, previous, new_votes);
+        }
+
+        Ok(())
+    }
+
+    /// Record a delegatee's new votes in the current block
+    fn _write_vote_checkpoint(&mut self, delegate: Address, previous: U256, new_votes: U256) {
+        push_checkpoint(
+            &mut self.vote_checkpoint_blocks.setter(delegate),
+            &mut self.vote_checkpoint_votes.setter(delegate),
+            new_votes,
+        );
+
+        evm::log(DelegateVotesChanged {
+            delegate,
+            previousVotes: previous,
+            newVotes: new_votes,
+        });
+    }
+
+    /// Reject lookups at or after the current block (still mutable)
+    fn _check_past(&self, timepoint: U256) -> Result<(), Vec<u8>> {
+        if timepoint >= U256::from(block::number()) {
+            return Err(FutureLookup {}.encode());
+        }
+        Ok(())
+    }
+
+    /// Charge a mint against the minter's allowance and rate-limit window
+    fn _consume_mint_allowance(&mut self, minter: Address, amount: U256) -> Result<(), Vec<u8>> {
+        let (allowance, rate_limit, window_start, window_minted) = {
+            let grant = self.minters.get(minter);
+            if !grant.active.get() {
+                return Err(NotMinter {}.encode());
+            }
+            (
+                grant.allowance.get(),
+                grant.rate_limit.get(),
+                grant.window_start.get(),
+                grant.window_minted.get(),
+            )
+        };
+
+        let (allowance, window_start, window_minted) = apply_mint_limits(
+            amount,
+            allowance,
+            rate_limit,
+            window_start,
+            window_minted,
+            self.mint_window.get(),
+            U256::from(block::timestamp()),
+        )?;
+
+        let mut grant = self.minters.setter(minter);
+        grant.allowance.set(allowance);
+        grant.window_start.set(window_start);
+        grant.window_minted.set(window_minted);
+
+        Ok(())
+    }
+
+    /// Whether a rate-limit window starting at `window_start` has elapsed
+    fn _window_expired(&self, window_start: U256) -> Result<bool, Vec<u8>> {
+        let window_end = checked_add(window_start, self.mint_window.get())?;
+        Ok(U256::from(block::timestamp()) >= window_end)
+    }
+
+    /// Internal approve function
+    fn _approve(&mut self, owner: Address, spender: Address, amount: U256) -> Result<(), Vec<u8>> {
+        if owner == Address::ZERO || spender == Address::ZERO {
+            return Err(InvalidAddress {}.encode());
+        }
+
+        self.allowances.setter(owner).setter(spender).set(amount);
+
+        evm::log(Approval {
+            owner,
+            spender,
+            value: amount,
+        });
+
+        Ok(())
+    }
+
+    /// EIP-712 domain separator (recomputed so it follows chain forks)
+    fn _domain_separator(&self) -> FixedBytes<32> {
+        eip712::domain_separator(&self.name.get_string(), DOMAIN_VERSION)
+    }
 
     /// Internal transfer function
     fn _transfer(&mut self, from: Address, to: Address, amount: U256) -> Result<(), Vec<u8>> {
@@ -288,10 +940,12 @@ impl SPPToken {
         }
 
         // Update balances
-        self.balances.setter(from).set(from_balance - amount);
+        self.balances.setter(from).set(checked_sub(from_balance, amount)?);
 
         let to_balance = self.balances.get(to);
-        self.balances.setter(to).set(to_balance + amount);
+        self.balances.setter(to).set(checked_add(to_balance, amount)?);
+
+        self._update_votes(from, to, amount)?;
 
         evm::log(Transfer {
             from,
@@ -315,15 +969,17 @@ impl SPPToken {
         }
 
         // Decrease balance
-        self.balances.setter(from).set(from_balance - amount);
+        self.balances.setter(from).set(checked_sub(from_balance, amount)?);
 
         // Decrease total supply
         let current_supply = self.total_supply.get();
-        self.total_supply.set(current_supply - amount);
+        self.total_supply.set(checked_sub(current_supply, amount)?);
 
         // Increase total burned
         let current_burned = self.total_burned.get();
-        self.total_burned.set(current_burned + amount);
+        self.total_burned.set(checked_add(current_burned, amount)?);
+
+        self._update_votes(from, Address::ZERO, amount)?;
 
         evm::log(Burn {
             from,
@@ -339,3 +995,114 @@ impl SPPToken {
         Ok(())
     }
 }
+
+// ==================== Supply Helpers ====================
+
+/// Total supply minus the non-circulating balances. Burns already reduce
+/// total supply, so they are not subtracted again.
+pub fn circulating_amount(total_supply: U256, excluded_balances: &[U256]) -> Result<U256, Vec<u8>> {
+    let mut circulating = total_supply;
+    for balance in excluded_balances {
+        circulating = checked_sub(circulating, *balance)?;
+    }
+    Ok(circulating)
+}
+
+// ==================== Minter Helpers ====================
+
+/// Apply a mint to a minter's lifetime allowance and rate-limit window;
+/// a fresh window starts at `now` once the previous one has elapsed
+/// @return (allowance, windowStart, windowMinted) after the mint
+pub fn apply_mint_limits(
+    amount: U256,
+    allowance: U256,
+    rate_limit: U256,
+    window_start: U256,
+    window_minted: U256,
+    window: U256,
+    now: U256,
+) -> Result<(U256, U256, U256), Vec<u8>> {
+    if amount > allowance {
+        return Err(MintAllowanceExceeded {}.encode());
+    }
+
+    let (window_start, window_minted) = if now >= checked_add(window_start, window)? {
+        (now, U256::from(0))
+    } else {
+        (window_start, window_minted)
+    };
+
+    let window_minted = checked_add(window_minted, amount)?;
+    if rate_limit > U256::from(0) && window_minted > rate_limit {
+        return Err(MintRateLimitExceeded {}.encode());
+    }
+
+    Ok((checked_sub(allowance, amount)?, window_start, window_minted))
+}
+
+// ==================== Checkpoint Helpers ====================
+
+/// Latest checkpointed value (zero if there are none)
+fn latest_checkpoint(values: &StorageVec<StorageU256>) -> U256 {
+    match values.len() {
+        0 => U256::from(0),
+        len => values.get(len - 1).unwrap_or_default(),
+    }
+}
+
+/// Write `value` for the current block, overwriting a checkpoint already
+/// written in this block
+fn push_checkpoint(
+    blocks: &mut StorageVec<StorageU256>,
+    values: &mut StorageVec<StorageU256>,
+    value: U256,
+) {
+    let current_block = U256::from(block::number());
+    let len = blocks.len();
+
+    if len > 0 && blocks.get(len - 1) == Some(current_block) {
+        if let Some(mut slot) = values.setter(len - 1) {
+            slot.set(value);
+        }
+    } else {
+        blocks.push(current_block);
+        values.push(value);
+    }
+}
+
+/// Value of the last checkpoint at or before `timepoint` (binary search)
+fn upper_lookup(
+    blocks: &StorageVec<StorageU256>,
+    values: &StorageVec<StorageU256>,
+    timepoint: U256,
+) -> U256 {
+    let count = checkpoints_at_or_before(
+        blocks.len(),
+        |i| blocks.get(i).unwrap_or_default(),
+        timepoint,
+    );
+
+    match count {
+        0 => U256::from(0),
+        count => values.get(count - 1).unwrap_or_default(),
+    }
+}
+
+/// Number of checkpoints written at or before `timepoint`, i.e. the first
+/// index whose block is after it (binary search over ascending blocks)
+pub fn checkpoints_at_or_before(
+    len: usize,
+    block_at: impl Fn(usize) -> U256,
+    timepoint: U256,
+) -> usize {
+    let (mut low, mut high) = (0usize, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if block_at(mid) > timepoint {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    high
+}
@@ -58,6 +58,116 @@ mod tests {
         assert_eq!(reward_amount - burn_amount, 90);
     }
 
+    #[test]
+    fn test_burn_split_policy() {
+        use spp_stylus_oracle::split_withheld;
+
+        // 15% withheld (tier override); 20% of it to treasury, 30% to community
+        let (burn, treasury, community) = split_withheld(
+            U256::from(1_000),
+            U256::from(1_500),
+            U256::from(2_000),
+            U256::from(3_000),
+        )
+        .unwrap();
+
+        assert_eq!(treasury, U256::from(30));
+        assert_eq!(community, U256::from(45));
+        assert_eq!(burn, U256::from(75));
+
+        // The withheld share rounds up, the cuts round down, dust is burned
+        let (burn, treasury, community) = split_withheld(
+            U256::from(1_001),
+            U256::from(1_000),
+            U256::from(5_000),
+            U256::from(0),
+        )
+        .unwrap();
+        assert_eq!(burn + treasury + community, U256::from(101));
+        assert_eq!(treasury, U256::from(50));
+        assert_eq!(burn, U256::from(51));
+    }
+
+    #[test]
+    fn test_emission_pro_rata_scaling() {
+        use spp_stylus_oracle::scale_pro_rata;
+
+        // Match claims exceeding the remaining budget are scaled pro-rata
+        let requested = [U256::from(600), U256::from(300), U256::from(100)];
+        let total = U256::from(1_000);
+        let scaled: Vec<U256> = requested
+            .iter()
+            .map(|r| scale_pro_rata(*r, total, U256::from(500)).unwrap())
+            .collect();
+        assert_eq!(scaled, vec![U256::from(300), U256::from(150), U256::from(50)]);
+
+        // Rounds down so the total stays within the budget
+        let scaled: Vec<U256> = requested
+            .iter()
+            .map(|r| scale_pro_rata(*r, total, U256::from(7)).unwrap())
+            .collect();
+        assert_eq!(scaled, vec![U256::from(4), U256::from(2), U256::from(0)]);
+
+        // Untouched when the budget covers everything
+        assert_eq!(scale_pro_rata(U256::from(600), total, total).unwrap(), U256::from(600));
+    }
+
+    #[test]
+    fn test_claimed_bitmap_indexing() {
+        use spp_stylus_oracle::claimed_bit;
+
+        // Leaf index -> (bitmap word, bit) used by the Merkle distributor
+        let (word_index, bit) = claimed_bit(U256::from(517));
+        assert_eq!(word_index, U256::from(2));
+        assert_eq!(bit, U256::from(1u64 << 5));
+
+        assert_eq!(claimed_bit(U256::from(0)), (U256::from(0), U256::from(1)));
+        assert_eq!(claimed_bit(U256::from(255)), (U256::from(0), U256::from(1) << 255));
+        assert_eq!(claimed_bit(U256::from(256)), (U256::from(1), U256::from(1)));
+
+        // Neighbouring leaves never share a bit
+        let (_, neighbour) = claimed_bit(U256::from(516));
+        assert!(bit & neighbour == U256::from(0));
+    }
+
+    #[test]
+    fn test_effort_curve_interpolation() {
+        use spp_stylus_oracle::effort_curve_factor;
+
+        // Threshold 20, floor 30%, cap 90,
+        // knees (20 -> 3000 bps), (60 -> 8000 bps), (90 -> 10000 bps)
+        let knees = [
+            (U256::from(20), U256::from(3_000)),
+            (U256::from(60), U256::from(8_000)),
+            (U256::from(90), U256::from(10_000)),
+        ];
+        let factor = |effort: u64| {
+            effort_curve_factor(
+                U256::from(effort),
+                U256::from(20),
+                U256::from(3_000),
+                U256::from(90),
+                &knees,
+            )
+        };
+
+        assert_eq!(factor(10), U256::from(0)); // below threshold
+        assert_eq!(factor(20), U256::from(3_000)); // floor
+        assert_eq!(factor(40), U256::from(5_500)); // halfway between first two knees
+        assert_eq!(factor(100), U256::from(10_000)); // saturated at cap
+
+        // Default linear curve (0 -> 0, 100 -> 10000) reproduces effort / 100
+        let linear = [(U256::from(0), U256::from(0)), (U256::from(100), U256::from(10_000))];
+        let linear_factor = effort_curve_factor(
+            U256::from(80),
+            U256::from(0),
+            U256::from(0),
+            U256::from(100),
+            &linear,
+        );
+        assert_eq!(linear_factor, U256::from(8_000));
+    }
+
     #[test]
     fn test_address_zero_check() {
         // Verify zero address constant
@@ -79,24 +189,37 @@ mod tests {
 
     #[test]
     fn test_power_stat_calculation() {
-        // Test athlete NFT power stat calculation
-        let total_runs = U256::from(300);
-        let matches = U256::from(5);
-        let highest_score = U256::from(120);
-
-        let avg_runs = total_runs / matches; // 60
-        let power = avg_runs + (highest_score / U256::from(2)); // 60 + 60 = 120
-
-        // Cap at 100
-        let capped_power = if power > U256::from(100) {
-            U256::from(100)
-        } else {
-            power
+        use spp_stylus_oracle::{
+            blend, form_ratio, range_score, DEFAULT_POWER_STRIKE_RATE_BPS, RUNS_PER_MATCH_RANGE,
+            STRIKE_RATE_RANGE,
         };
 
-        assert_eq!(avg_runs, U256::from(60));
-        assert_eq!(power, U256::from(120));
-        assert_eq!(capped_power, U256::from(100));
+        // Power blends strike rate and runs per match (default 50/50)
+        let power = |runs: u64, balls: u64, matches: u64| {
+            let strike_rate = form_ratio(U256::from(runs), 10_000, U256::from(balls))
+                .unwrap()
+                .unwrap();
+            let runs_per_match = form_ratio(U256::from(runs), 100, U256::from(matches))
+                .unwrap()
+                .unwrap();
+            let weight = U256::from(DEFAULT_POWER_STRIKE_RATE_BPS);
+            blend(&[
+                (range_score(strike_rate, STRIKE_RATE_RANGE).unwrap(), weight),
+                (
+                    range_score(runs_per_match, RUNS_PER_MATCH_RANGE).unwrap(),
+                    U256::from(10_000) - weight,
+                ),
+            ])
+            .unwrap()
+        };
+
+        // 330 runs off 300 balls in 5 matches: strike rate 110 scores 40,
+        // 66 runs per match is past full marks (60) and scores 100
+        assert_eq!(power(330, 300, 5), U256::from(70));
+        // Both metrics clamp, so Power never exceeds 100
+        assert_eq!(power(900, 300, 5), U256::from(100));
+        // Strike rate below 50 scores 0; 12 runs per match is a fifth of full marks
+        assert_eq!(power(60, 150, 5), U256::from(10));
     }
 
     #[test]
@@ -141,6 +264,309 @@ mod tests {
         assert_eq!(match_id.len(), 32);
     }
 
+    #[test]
+    fn test_mul_div_rounding() {
+        use spp_stylus_oracle::{mul_div, Rounding};
+
+        let a = U256::from(10);
+        let b = U256::from(5);
+        let d = U256::from(3); // 50 / 3 = 16.67
+
+        assert_eq!(mul_div(a, b, d, Rounding::Down).unwrap(), U256::from(16));
+        assert_eq!(mul_div(a, b, d, Rounding::Up).unwrap(), U256::from(17));
+        assert_eq!(mul_div(a, b, d, Rounding::Nearest).unwrap(), U256::from(17));
+        assert!(mul_div(a, b, U256::ZERO, Rounding::Down).is_err());
+
+        // Intermediate product wider than 256 bits is fine if the result fits
+        assert_eq!(
+            mul_div(U256::MAX, U256::from(2), U256::from(4), Rounding::Down).unwrap(),
+            U256::MAX / U256::from(2)
+        );
+        assert!(mul_div(U256::MAX, U256::from(2), U256::from(1), Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_checked_math_errors() {
+        use spp_stylus_oracle::{checked_add, checked_mul, checked_sub};
+
+        assert_eq!(checked_add(U256::from(1), U256::from(2)).unwrap(), U256::from(3));
+        assert!(checked_add(U256::MAX, U256::from(1)).is_err());
+        assert!(checked_sub(U256::from(1), U256::from(2)).is_err());
+        assert!(checked_mul(U256::MAX, U256::from(2)).is_err());
+
+        // Overflow and Underflow revert with different selectors
+        let overflow = checked_add(U256::MAX, U256::from(1)).unwrap_err();
+        let underflow = checked_sub(U256::ZERO, U256::from(1)).unwrap_err();
+        assert_ne!(overflow[..4], underflow[..4]);
+    }
+
+    #[test]
+    fn test_circulating_supply_formula() {
+        use spp_stylus_oracle::circulating_amount;
+
+        // 1,000,000 minted, 50,000 burned: burns are already out of the supply
+        let total_supply = U256::from(950_000);
+        // Treasury plus two locked balances
+        let excluded = [U256::from(200_000), U256::from(100_000), U256::from(25_000)];
+
+        assert_eq!(circulating_amount(total_supply, &excluded).unwrap(), U256::from(625_000));
+        assert_eq!(circulating_amount(total_supply, &[]).unwrap(), total_supply);
+        // Excluding more than the supply reverts instead of wrapping
+        assert!(circulating_amount(U256::from(100), &[U256::from(101)]).is_err());
+    }
+
+    #[test]
+    fn test_minter_rate_limit_window() {
+        use spp_stylus_oracle::apply_mint_limits;
+
+        // Allowance 2,500, at most 1,000 per 1-day window
+        let window = U256::from(86_400);
+        let rate_limit = U256::from(1_000);
+        let mut state = (U256::from(2_500), U256::from(0), U256::from(0));
+        let mut mint = |now: u64, amount: u64| -> bool {
+            let (allowance, window_start, window_minted) = state;
+            match apply_mint_limits(
+                U256::from(amount),
+                allowance,
+                rate_limit,
+                window_start,
+                window_minted,
+                window,
+                U256::from(now),
+            ) {
+                Ok(next) => {
+                    state = next;
+                    true
+                }
+                Err(_) => false,
+            }
+        };
+
+        assert!(mint(100_000, 800));
+        assert!(!mint(100_500, 300)); // Window already has 800
+        assert!(mint(186_400, 1_000)); // New window
+        assert!(!mint(272_800, 800)); // Only 700 allowance left
+        assert!(mint(272_800, 700));
+    }
+
+    #[test]
+    fn test_checkpoint_upper_lookup() {
+        use spp_stylus_oracle::checkpoints_at_or_before;
+
+        // Value at a past block = last checkpoint written at or before it
+        let blocks = [U256::from(10), U256::from(15), U256::from(22)];
+        let count = |timepoint: u64| {
+            checkpoints_at_or_before(blocks.len(), |i| blocks[i], U256::from(timepoint))
+        };
+
+        assert_eq!(count(9), 0); // Before the first checkpoint: zero votes
+        assert_eq!(count(10), 1);
+        assert_eq!(count(14), 1);
+        assert_eq!(count(15), 2);
+        assert_eq!(count(30), 3);
+        assert_eq!(checkpoints_at_or_before(0, |_| U256::ZERO, U256::from(5)), 0);
+    }
+
+    #[test]
+    fn test_staking_reward_per_token() {
+        use spp_stylus_oracle::{accrue_reward_per_token, earned_rewards};
+
+        // Rewards are shared pro-rata to stake over time (10 tokens/s)
+        // Alice stakes 100 alone for 10s, then Bob stakes 300 for 10s
+        let zero = U256::ZERO;
+        let rpt_1 = accrue_reward_per_token(zero, U256::from(100), U256::from(100)).unwrap();
+        let rpt_2 = accrue_reward_per_token(rpt_1, U256::from(100), U256::from(400)).unwrap();
+
+        let alice = earned_rewards(U256::from(100), rpt_2, zero, zero).unwrap();
+        let bob = earned_rewards(U256::from(300), rpt_2, rpt_1, zero).unwrap();
+
+        assert_eq!(alice, U256::from(125));
+        assert_eq!(bob, U256::from(75));
+
+        // Already-owed rewards carry over
+        assert_eq!(earned_rewards(U256::from(100), rpt_2, rpt_2, alice).unwrap(), alice);
+    }
+
+    #[test]
+    fn test_prediction_pool_payout() {
+        use spp_stylus_oracle::{settlement_fee, winning_payout};
+
+        // YES wins: YES stakers split the NO pool minus a 5% burned fee
+        let (yes_pool, no_pool) = (U256::from(1_000), U256::from(600));
+        let fee = settlement_fee(no_pool, U256::from(500)).unwrap();
+        let payout = |stake: u64| winning_payout(U256::from(stake), yes_pool, no_pool, fee).unwrap();
+
+        assert_eq!(fee, U256::from(30));
+        assert_eq!(payout(250), U256::from(392));
+        assert_eq!(payout(750), U256::from(1_177));
+        // Everything but the fee (and rounding dust) is paid out
+        assert!(payout(250) + payout(750) <= yes_pool + no_pool - fee);
+    }
+
+    #[test]
+    fn test_erc721_interface_id() {
+        // ERC-165 interface ID = XOR of the interface's function selectors
+        use spp_stylus_oracle::{
+            is_supported_interface, ERC721_RECEIVED, INTERFACE_ERC165, INTERFACE_ERC721,
+            INTERFACE_ERC721_METADATA,
+        };
+        use stylus_sdk::alloy_primitives::keccak256;
+
+        let selector = |signature: &str| -> u32 {
+            let hash = keccak256(signature.as_bytes());
+            u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+        };
+
+        let erc721 = [
+            "balanceOf(address)",
+            "ownerOf(uint256)",
+            "safeTransferFrom(address,address,uint256,bytes)",
+            "safeTransferFrom(address,address,uint256)",
+            "transferFrom(address,address,uint256)",
+            "approve(address,uint256)",
+            "setApprovalForAll(address,bool)",
+            "getApproved(uint256)",
+            "isApprovedForAll(address,address)",
+        ];
+        let metadata = ["name()", "symbol()", "tokenURI(uint256)"];
+
+        let xor = |sigs: &[&str]| sigs.iter().fold(0u32, |id, sig| id ^ selector(sig));
+        assert_eq!(xor(&erc721).to_be_bytes(), INTERFACE_ERC721);
+        assert_eq!(xor(&metadata).to_be_bytes(), INTERFACE_ERC721_METADATA);
+        assert_eq!(selector("supportsInterface(bytes4)").to_be_bytes(), INTERFACE_ERC165);
+        assert_eq!(
+            selector("onERC721Received(address,address,uint256,bytes)").to_be_bytes(),
+            ERC721_RECEIVED
+        );
+
+        // supportsInterface answers for exactly these three
+        assert!(is_supported_interface(INTERFACE_ERC165));
+        assert!(is_supported_interface(INTERFACE_ERC721));
+        assert!(is_supported_interface(INTERFACE_ERC721_METADATA));
+        assert!(!is_supported_interface([0xff, 0xff, 0xff, 0xff])); // ERC-165 invalid ID
+        assert!(!is_supported_interface(ERC721_RECEIVED));
+    }
+
+    #[test]
+    fn test_nft_metadata_encoding() {
+        use spp_stylus_oracle::{base64_encode, escape_json, escape_xml, AthleteCard};
+
+        // RFC 4648 test vectors
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+
+        // Names cannot break out of the JSON string or inject SVG markup
+        assert_eq!(escape_json("A \"B\" \\ C"), "A \\\"B\\\" \\\\ C");
+        assert_eq!(escape_xml("<script>&'\""), "&lt;script&gt;&amp;&apos;&quot;");
+
+        let card = AthleteCard {
+            token_id: U256::from(7),
+            name: "Virat \"King\"",
+            power: U256::from(88),
+            speed: U256::from(120),
+            accuracy: U256::from(50),
+            matches_played: U256::from(12),
+            total_runs: U256::from(640),
+            total_wickets: U256::from(3),
+            highest_score: U256::from(113),
+            best_bowling: U256::from(2),
+            visibility_level: U256::from(1),
+            external_url: None,
+        };
+
+        let json = card.to_json();
+        assert!(json.starts_with("{\"name\":\"Virat \\\"King\\\" #7\""));
+        assert!(json.contains("{\"trait_type\":\"Power\",\"value\":88,\"max_value\":100}"));
+        assert!(!json.contains("external_url"));
+        assert!(card.to_svg().contains("Virat &quot;King&quot;"));
+        assert!(card.to_token_uri().starts_with("data:application/json;base64,"));
+    }
+
+    #[test]
+    fn test_match_history_pagination() {
+        use spp_stylus_oracle::{history_page, recent_history};
+
+        // Pages clamp to a 7-entry history, even for huge offsets and limits
+        assert_eq!(history_page(7, U256::from(0), U256::from(3)), 0..3);
+        assert_eq!(history_page(7, U256::from(6), U256::from(3)), 6..7);
+        assert!(history_page(7, U256::from(10), U256::from(3)).is_empty());
+        assert_eq!(history_page(7, U256::from(2), U256::MAX), 2..7);
+        assert!(history_page(7, U256::MAX, U256::MAX).is_empty());
+
+        // "Last N" covers the newest entries (the view walks them backwards)
+        assert_eq!(recent_history(7, U256::from(3)), 4..7);
+        assert_eq!(recent_history(7, U256::from(20)), 0..7);
+        assert!(recent_history(0, U256::from(5)).is_empty());
+    }
+
+    #[test]
+    fn test_recency_decay_form() {
+        use spp_stylus_oracle::{blend, decay_fold, form_ratio, range_score};
+
+        // 85% decay: a slow start followed by quick innings pulls the
+        // strike rate towards current form
+        let decay = U256::from(8_500);
+        let innings = [(10u64, 40u64), (10, 40), (10, 40), (40, 20), (40, 20)];
+        let (mut runs, mut balls) = (U256::ZERO, U256::ZERO);
+        for (r, b) in innings {
+            runs = decay_fold(runs, U256::from(r), decay).unwrap();
+            balls = decay_fold(balls, U256::from(b), decay).unwrap();
+        }
+
+        let strike_rate = form_ratio(runs, 10_000, balls).unwrap().unwrap();
+        // Career strike rate is 110/160 = 68.75; form is well above it
+        assert!(strike_rate > U256::from(6_875));
+        assert!(strike_rate < U256::from(20_000));
+        assert_eq!(form_ratio(runs, 10_000, U256::ZERO).unwrap(), None);
+
+        // Linear 0-100 score between a strike rate of 50 and 200, clamped
+        let score = range_score(strike_rate, (5_000, 20_000)).unwrap();
+        assert!(score > U256::ZERO && score < U256::from(100));
+        assert_eq!(range_score(U256::from(4_000), (5_000, 20_000)).unwrap(), U256::ZERO);
+        // Inverted range: a lower economy scores higher
+        assert_eq!(range_score(U256::from(400), (1_200, 400)).unwrap(), U256::from(100));
+        assert_eq!(range_score(U256::from(800), (1_200, 400)).unwrap(), U256::from(50));
+
+        // Weighted blend of scores, weights in bps
+        let blended = blend(&[
+            (U256::from(80), U256::from(5_000)),
+            (U256::from(40), U256::from(5_000)),
+        ])
+        .unwrap();
+        assert_eq!(blended, U256::from(60));
+    }
+
+    #[test]
+    fn test_reward_in_base_units() {
+        use spp_stylus_oracle::{effort_curve_factor, performance_reward, TOKEN_UNIT};
+
+        // Nifty Fifty: 50 SPP base, 1.5x, 33% effort on the default linear curve
+        // Whole-token math truncated this to (50 * 33 / 100) * 15 / 10 = 24
+        let linear = [(U256::from(0), U256::from(0)), (U256::from(100), U256::from(10_000))];
+        let effort_factor = effort_curve_factor(
+            U256::from(33),
+            U256::from(0),
+            U256::from(0),
+            U256::from(100),
+            &linear,
+        );
+        let reward =
+            performance_reward(U256::from(50) * TOKEN_UNIT, U256::from(15), effort_factor)
+                .unwrap();
+
+        // 24.75 SPP exactly
+        assert_eq!(reward, U256::from(2475) * TOKEN_UNIT / U256::from(100));
+
+        // Zero effort factor (below the threshold) earns nothing
+        assert_eq!(
+            performance_reward(U256::from(50) * TOKEN_UNIT, U256::from(15), U256::ZERO).unwrap(),
+            U256::ZERO
+        );
+    }
+
     #[test]
     fn test_erc20_decimals() {
         // SPP Token uses 18 decimals (standard)